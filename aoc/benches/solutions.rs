@@ -0,0 +1,219 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, SamplingMode};
+use solver::{input, Part, Solver};
+
+const BOTH: &[Part] = &[Part::A, Part::B];
+const A_ONLY: &[Part] = &[Part::A];
+
+/// Benches parsing and each part on their own, so a slow parser doesn't hide in a part's time.
+/// Slow days take a flat ten samples instead of criterion's default hundred.
+fn bench<S: Solver>(
+    c: &mut Criterion,
+    name: &str,
+    embedded: &'static str,
+    parts: &[Part],
+    slow: bool,
+) {
+    let input = input::or_embedded(S::DAY, embedded);
+    let parsed = S::parse(&input).unwrap_or_else(|err| panic!("{name}: {err}"));
+    let params = S::Params::default();
+
+    let mut group = c.benchmark_group(name);
+    if slow {
+        group.sampling_mode(SamplingMode::Flat).sample_size(10);
+    }
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    for part in parts {
+        group.bench_function(part.to_string(), |b| match part {
+            Part::A => {
+                b.iter(|| S::part_a(black_box(&parsed), &params).map(|answer| answer.to_string()))
+            }
+            Part::B => {
+                b.iter(|| S::part_b(black_box(&parsed), &params).map(|answer| answer.to_string()))
+            }
+        });
+    }
+    group.finish();
+}
+
+fn solutions(c: &mut Criterion) {
+    bench::<day01::Day01>(
+        c,
+        "day01",
+        include_str!("../../day01/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day02::Day02>(
+        c,
+        "day02",
+        include_str!("../../day02/src/inputs.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day03::Day03>(
+        c,
+        "day03",
+        include_str!("../../day03/src/inputs.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day04::Day04>(
+        c,
+        "day04",
+        include_str!("../../day04/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day04_nom::Day04Nom>(
+        c,
+        "day04_nom",
+        include_str!("../../day04_nom/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day05::Day05>(
+        c,
+        "day05",
+        include_str!("../../day05/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day06::Day06>(
+        c,
+        "day06",
+        include_str!("../../day06/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day07::Day07>(
+        c,
+        "day07",
+        include_str!("../../day07/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day08::Day08>(
+        c,
+        "day08",
+        include_str!("../../day08/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day09::Day09>(
+        c,
+        "day09",
+        include_str!("../../day09/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day10::Day10>(
+        c,
+        "day10",
+        include_str!("../../day10/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day11::Day11>(
+        c,
+        "day11",
+        include_str!("../../day11/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day12::Day12>(
+        c,
+        "day12",
+        include_str!("../../day12/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day13::Day13>(
+        c,
+        "day13",
+        include_str!("../../day13/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day14::Day14>(
+        c,
+        "day14",
+        include_str!("../../day14/src/input.txt"),
+        BOTH,
+        true,
+    );
+    bench::<day15::Day15>(
+        c,
+        "day15",
+        include_str!("../../day15/src/input.txt"),
+        BOTH,
+        true,
+    );
+    bench::<day16::Day16>(
+        c,
+        "day16",
+        include_str!("../../day16/src/input.txt"),
+        A_ONLY,
+        true,
+    );
+    bench::<day17::Day17>(
+        c,
+        "day17",
+        include_str!("../../day17/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day18::Day18>(
+        c,
+        "day18",
+        include_str!("../../day18/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day20::Day20>(
+        c,
+        "day20",
+        include_str!("../../day20/src/input.txt"),
+        BOTH,
+        true,
+    );
+    bench::<day20_double_linked_list::Day20DoubleLinkedList>(
+        c,
+        "day20_double_linked_list",
+        include_str!("../../day20_double_linked_list/src/input.txt"),
+        A_ONLY,
+        true,
+    );
+    bench::<day21::Day21>(
+        c,
+        "day21",
+        include_str!("../../day21/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day22::Day22>(
+        c,
+        "day22",
+        include_str!("../../day22/src/input.txt"),
+        BOTH,
+        false,
+    );
+    bench::<day23::Day23>(
+        c,
+        "day23",
+        include_str!("../../day23/src/input.txt"),
+        BOTH,
+        true,
+    );
+    bench::<day25::Day25>(
+        c,
+        "day25",
+        include_str!("../../day25/src/input.txt"),
+        A_ONLY,
+        false,
+    );
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
@@ -0,0 +1,155 @@
+//! Regression check of every solution against the known answers in `answers.toml`.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::Deserialize;
+use solver::{Part, Puzzle};
+
+use crate::puzzles;
+
+/// Known answers keyed by day (`day04`), then by the name of the input (`real`).
+pub type Answers = BTreeMap<String, BTreeMap<String, Expected>>;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    /// Path of the input, relative to the answers file.
+    pub input: PathBuf,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+impl Expected {
+    fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+}
+
+pub fn load(path: &Path) -> Result<Answers, String> {
+    let answers = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    toml::from_str(&answers).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub enum Status {
+    Pass,
+    Mismatch {
+        answer: String,
+    },
+    /// The input couldn't be read or parsed.
+    Fail(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Mismatch { answer } => write!(f, "mismatch, got {answer:?}"),
+            Status::Fail(reason) => write!(f, "fail, {reason}"),
+        }
+    }
+}
+
+pub struct Outcome {
+    pub puzzle: &'static str,
+    pub input: String,
+    pub part: Part,
+    pub status: Status,
+    /// Parsing and solving the part.
+    pub elapsed: Duration,
+}
+
+/// Runs every solution, or only the given day's, on each of its day's inputs with a known answer.
+/// Parts a solution doesn't solve, like part B of an alternative implementation, are left out.
+pub fn run(answers: &Answers, base: &Path, day: Option<u8>) -> Vec<Outcome> {
+    let mut outcomes = vec![];
+    for puzzle in puzzles::all() {
+        if day.is_some_and(|day| day != puzzle.day()) {
+            continue;
+        }
+        let Some(inputs) = answers.get(&format!("day{:02}", puzzle.day())) else {
+            continue;
+        };
+        for (name, expected) in inputs {
+            let input = fs::read_to_string(base.join(&expected.input));
+            for part in [Part::A, Part::B] {
+                if let Some(answer) = expected.answer(part) {
+                    outcomes.extend(check(puzzle.as_ref(), name, &input, part, answer));
+                }
+            }
+        }
+    }
+    outcomes
+}
+
+fn check(
+    puzzle: &dyn Puzzle,
+    name: &str,
+    input: &io::Result<String>,
+    part: Part,
+    expected: &str,
+) -> Option<Outcome> {
+    let start = Instant::now();
+    let status = match input {
+        Err(err) => Status::Fail(format!("failed to read input: {err}")),
+        Ok(input) => match puzzle.solve(input, &[part]) {
+            Err(err) => Status::Fail(format!("failed to parse input: {err}")),
+            Ok(solution) => match solution.answers.into_iter().next()?.value {
+                Some(answer) if same(&answer, expected) => Status::Pass,
+                Some(answer) => Status::Mismatch { answer },
+                None => return None,
+            },
+        },
+    };
+    Some(Outcome {
+        puzzle: puzzle.name(),
+        input: name.to_string(),
+        part,
+        status,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Compares line by line ignoring trailing whitespace, which editors tend to strip from
+/// multi-line answers like day 10's image.
+fn same(answer: &str, expected: &str) -> bool {
+    answer
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .eq(expected.trim_end().lines().map(str::trim_end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let answers: Answers = toml::from_str(
+            r#"
+            [day01.real]
+            input = "day01/src/input.txt"
+            a = "71471"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            answers["day01"]["real"],
+            Expected {
+                input: PathBuf::from("day01/src/input.txt"),
+                a: Some("71471".to_string()),
+                b: None,
+            }
+        );
+        assert!(same("#  #   \n ## \n", "#  #\n ##"));
+        assert!(!same("#  #\n ##", "#  #\n##"));
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use clap::{Parser, Subcommand};
+use render::{Animation, Format, Options, Recorder};
+use solver::{input::Source, Part};
+use tracing_subscriber::filter::LevelFilter;
+
+mod check;
+mod puzzles;
+mod report;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Most verbose debug output of the solutions to log to stderr, e.g. debug or trace
+    #[arg(long, global = true, default_value = "warn")]
+    log: LevelFilter,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day's solution
+    Run {
+        /// Day of the puzzle, 1 to 25
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to run, a or b, both parts are run when omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Path to the puzzle input, `-` for stdin, defaults to $AOC_INPUT_DIR/2022/dayNN.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Print a JSON object per part with the answer, timings and a hash of the input
+        #[arg(long, conflicts_with = "visualise")]
+        json: bool,
+        /// Record the simulation while solving, as ascii, png, gif or apng
+        #[arg(long, value_name = "FORMAT")]
+        visualise: Option<Format>,
+        /// Where to record the part to, defaults to dayNN-PART.EXT, ascii goes to stdout without it
+        #[arg(short, long, requires = "visualise", requires = "part")]
+        output: Option<PathBuf>,
+        /// Record every nth step of the simulation
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// Milliseconds between frames
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+        /// Pixels per cell in images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+    },
+    /// Check every solution against the known answers
+    Check {
+        /// Answers file, input paths in it are relative to it
+        #[arg(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Only check this day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Make up an input for a day, to stress test or benchmark its solution with
+    Gen {
+        /// Day of the puzzle, 1 to 25
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Size of the input, what it counts depends on the day, defaults to the real input's
+        #[arg(short, long)]
+        size: Option<usize>,
+        /// Seed to make the same input again with, a new one is picked and printed to stderr
+        /// when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Where to write the input, defaults to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// List every solution in the workspace
+    List,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    tracing_subscriber::fmt()
+        .with_max_level(cli.log)
+        .with_writer(io::stderr)
+        .init();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            json,
+            visualise,
+            output,
+            every,
+            delay,
+            scale,
+        } => {
+            let Some(puzzle) = puzzles::find(day) else {
+                eprintln!("day {day} has no solution");
+                return ExitCode::FAILURE;
+            };
+            let animation = match visualise {
+                Some(_) => match puzzles::find_animation(day) {
+                    Some(animation) => Some(animation),
+                    None => {
+                        eprintln!("day {day} has no visualisation");
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => None,
+            };
+            let source = Source::new(day, input.as_deref());
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("failed to read {}: {err}", source.describe());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::A, Part::B],
+            };
+            if let (Some(format), Some(animation)) = (visualise, animation) {
+                let options = Options {
+                    format,
+                    output,
+                    every,
+                    delay: Duration::from_millis(delay),
+                    scale,
+                };
+                return record(animation.as_ref(), &input, &source, &parts, options);
+            }
+            let solution = match puzzle.solve(&input, &parts) {
+                Ok(solution) => solution,
+                Err(err) => {
+                    eprintln!("failed to parse {}: {err}", source.describe());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let input_sha256 = report::input_sha256(&input);
+            for answer in &solution.answers {
+                let Some(value) = &answer.value else {
+                    // only an explicitly requested part is an error, e.g. day 25 has no part b
+                    if parts.len() == 1 {
+                        eprintln!("day {day} part {} has no solution", answer.part);
+                        return ExitCode::FAILURE;
+                    }
+                    continue;
+                };
+                if json {
+                    let line =
+                        report::Line::new(puzzle.as_ref(), &solution, answer, value, &input_sha256);
+                    println!("{line}");
+                } else {
+                    println!("{value}");
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Check { answers, day } => {
+            let expected = match check::load(&answers) {
+                Ok(expected) => expected,
+                Err(err) => {
+                    eprintln!("failed to load answers: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let base = answers.parent().unwrap_or(Path::new(""));
+            let outcomes = check::run(&expected, base, day);
+            for outcome in &outcomes {
+                println!(
+                    "{:<26} {:<8} {} {:>10.2?}  {}",
+                    outcome.puzzle, outcome.input, outcome.part, outcome.elapsed, outcome.status
+                );
+            }
+            let passed = outcomes
+                .iter()
+                .filter(|outcome| matches!(outcome.status, check::Status::Pass))
+                .count();
+            println!("{passed} of {} passed", outcomes.len());
+            if passed == outcomes.len() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let Some(generator) = puzzles::find_generator(day) else {
+                eprintln!("day {day} has no generator");
+                return ExitCode::FAILURE;
+            };
+            let seed = seed.unwrap_or_else(|| {
+                let seed = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+                eprintln!("seed {seed}");
+                seed
+            });
+            let input = generator.generate(size, seed);
+            match output {
+                Some(path) => {
+                    if let Err(err) = fs::write(&path, input) {
+                        eprintln!("failed to write {}: {err}", path.display());
+                        return ExitCode::FAILURE;
+                    }
+                }
+                None => print!("{input}"),
+            }
+            ExitCode::SUCCESS
+        }
+        Command::List => {
+            for puzzle in puzzles::all() {
+                println!("{:>2} {}", puzzle.day(), puzzle.name());
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// Solves the parts like `run` does while recording their simulation.
+fn record(
+    animation: &dyn Animation,
+    input: &str,
+    source: &Source,
+    parts: &[Part],
+    options: Options,
+) -> ExitCode {
+    let day = animation.day();
+    for &part in parts {
+        let mut options = options.clone();
+        if options.output.is_none() && options.format != Format::Ascii {
+            let extension = options.format.extension();
+            options.output = Some(PathBuf::from(format!("day{day:02}-{part}.{extension}")));
+        }
+        let output = options.output.clone();
+        let mut recorder = match Recorder::new(options) {
+            Ok(recorder) => recorder,
+            Err(err) => {
+                eprintln!("failed to record day {day} part {part}: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let answer = match animation.animate(input, part, &mut recorder) {
+            Ok(answer) => answer,
+            Err(err) => {
+                eprintln!("failed to parse {}: {err}", source.describe());
+                return ExitCode::FAILURE;
+            }
+        };
+        let Some(answer) = answer else {
+            if parts.len() == 1 {
+                eprintln!("day {day} part {part} has no visualisation");
+                return ExitCode::FAILURE;
+            }
+            continue;
+        };
+        match recorder.finish() {
+            Ok(frames) => {
+                if let Some(output) = output {
+                    eprintln!(
+                        "recorded {frames} frames of part {part} to {}",
+                        output.display()
+                    );
+                }
+            }
+            Err(err) => {
+                eprintln!("failed to record day {day} part {part}: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        println!("{answer}");
+    }
+    ExitCode::SUCCESS
+}
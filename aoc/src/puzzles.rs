@@ -0,0 +1,125 @@
+use generator::Generator;
+use render::Animation;
+use solver::{Day, Puzzle};
+
+macro_rules! puzzles {
+    ($trait:ident; $($krate:ident::$solver:ident),* $(,)?) => {
+        vec![$(Box::new(Day::<$krate::$solver>::new(stringify!($krate))) as Box<dyn $trait>),*]
+    };
+}
+
+/// Every solution in the workspace, ordered by day. When a day has alternative implementations
+/// the first one listed is the one `aoc run` uses.
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    puzzles![
+        Puzzle;
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day04_nom::Day04Nom,
+        day05::Day05,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day10::Day10,
+        day11::Day11,
+        day12::Day12,
+        day13::Day13,
+        day14::Day14,
+        day15::Day15,
+        day16::Day16,
+        day17::Day17,
+        day18::Day18,
+        day20::Day20,
+        day20_double_linked_list::Day20DoubleLinkedList,
+        day21::Day21,
+        day22::Day22,
+        day23::Day23,
+        day25::Day25,
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Puzzle>> {
+    all().into_iter().find(|puzzle| puzzle.day() == day)
+}
+
+/// The solutions that can record their simulation for `aoc run --visualise`.
+pub fn animations() -> Vec<Box<dyn Animation>> {
+    puzzles![
+        Animation;
+        day09::Day09,
+        day14::Day14,
+        day17::Day17,
+        day20_double_linked_list::Day20DoubleLinkedList,
+        day22::Day22,
+        day23::Day23,
+    ]
+}
+
+pub fn find_animation(day: u8) -> Option<Box<dyn Animation>> {
+    animations().into_iter().find(|puzzle| puzzle.day() == day)
+}
+
+/// The solutions that can make up inputs for `aoc gen`, one per day as alternative
+/// implementations of a day share its input.
+pub fn generators() -> Vec<Box<dyn Generator>> {
+    puzzles![
+        Generator;
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day05::Day05,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day10::Day10,
+        day11::Day11,
+        day12::Day12,
+        day13::Day13,
+        day14::Day14,
+        day15::Day15,
+        day16::Day16,
+        day17::Day17,
+        day18::Day18,
+        day20::Day20,
+        day21::Day21,
+        day22::Day22,
+        day23::Day23,
+        day25::Day25,
+    ]
+}
+
+pub fn find_generator(day: u8) -> Option<Box<dyn Generator>> {
+    generators().into_iter().find(|puzzle| puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::Part;
+
+    #[test]
+    fn it_works() {
+        for generator in generators() {
+            for seed in 0..3 {
+                let input = generator.generate(Some(10), seed);
+                assert_eq!(input, generator.generate(Some(10), seed));
+                let solution = match generator.solve(&input, &[Part::A]) {
+                    Ok(solution) => solution,
+                    Err(err) => panic!("day {} seed {seed}: {err}\n{input}", generator.day()),
+                };
+                // only part A, some part Bs search the whole area of the real input however
+                // small the generated one is
+                assert!(
+                    solution.answers[0].value.is_some(),
+                    "day {} seed {seed} has no answer for part a\n{input}",
+                    generator.day()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Machine readable output of `aoc run --json`, a JSON object per line for each part.
+
+use std::fmt;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use solver::{Answer, Puzzle, Solution};
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// Crate implementing the puzzle, tells alternative implementations of a day apart.
+    pub puzzle: &'static str,
+    pub part: String,
+    pub answer: &'a str,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+    /// Identifies the input without publishing it.
+    pub input_sha256: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(
+        puzzle: &dyn Puzzle,
+        solution: &Solution,
+        answer: &Answer,
+        value: &'a str,
+        input_sha256: &'a str,
+    ) -> Self {
+        Self {
+            day: puzzle.day(),
+            puzzle: puzzle.name(),
+            part: answer.part.to_string(),
+            answer: value,
+            parse_time_ns: solution.parse_time.as_nanos() as u64,
+            solve_time_ns: answer.solve_time.as_nanos() as u64,
+            input_sha256,
+        }
+    }
+}
+
+impl fmt::Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{json}")
+    }
+}
+
+pub fn input_sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use solver::Part;
+
+    use crate::puzzles;
+
+    #[test]
+    fn it_works() {
+        let hash = input_sha256("abc");
+        assert_eq!(
+            hash,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let puzzle = puzzles::find(6).unwrap();
+        let solution = Solution {
+            parse_time: Duration::from_micros(2),
+            answers: vec![Answer {
+                part: Part::A,
+                value: Some("7".to_string()),
+                solve_time: Duration::from_nanos(300),
+            }],
+        };
+        let line = Line::new(puzzle.as_ref(), &solution, &solution.answers[0], "7", &hash);
+        assert_eq!(
+            line.to_string(),
+            format!(
+                r#"{{"day":6,"puzzle":"day06","part":"a","answer":"7","parse_time_ns":2000,"solve_time_ns":300,"input_sha256":"{hash}"}}"#
+            )
+        );
+    }
+}
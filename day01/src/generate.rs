@@ -0,0 +1,20 @@
+//! Inventories of calories, `size` counts the elves.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day01;
+
+impl Generate for Day01 {
+    const SIZE: usize = 236;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let inventories: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                (0..rng.gen_range(1..=15))
+                    .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                    .collect()
+            })
+            .collect();
+        inventories.join("\n")
+    }
+}
@@ -0,0 +1,265 @@
+mod generate;
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+pub use solver::ReadError;
+use solver::{parse, ParseError, Solver};
+
+/// The calories of each item one elf carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<usize>,
+}
+
+impl Elf {
+    pub fn calories(&self) -> usize {
+        self.items.iter().sum()
+    }
+}
+
+/// Every elf's items, in the order the elves are listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// The `k` most calories any elves carry, most first.
+    pub fn top(&self, k: usize) -> Vec<usize> {
+        top(self.elves.iter().map(Elf::calories), k)
+    }
+
+    /// `None` without elves.
+    pub fn stats(&self) -> Option<Stats> {
+        Stats::new(
+            self.elves
+                .iter()
+                .map(|elf| (elf.calories(), elf.items.len())),
+        )
+    }
+}
+
+/// Summary of the calories the elves carry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    /// The mean of the middle two for an even number of elves.
+    pub median: f64,
+    /// How many items each elf carries, in the order the elves are listed.
+    pub item_counts: Vec<usize>,
+}
+
+impl Stats {
+    /// From the calories and item count of each elf.
+    fn new(elves: impl IntoIterator<Item = (usize, usize)>) -> Option<Self> {
+        let (mut calories, item_counts): (Vec<_>, Vec<_>) = elves.into_iter().unzip();
+        if calories.is_empty() {
+            return None;
+        }
+        let mean = calories.iter().sum::<usize>() as f64 / calories.len() as f64;
+        calories.sort_unstable();
+        let mid = calories.len() / 2;
+        let median = if calories.len() % 2 == 0 {
+            (calories[mid - 1] + calories[mid]) as f64 / 2.0
+        } else {
+            calories[mid] as f64
+        };
+        Some(Self {
+            mean,
+            median,
+            item_counts,
+        })
+    }
+}
+
+/// The elves in an inventory read a line at a time, holding on to one elf's items at most.
+pub struct Elves<R> {
+    lines: io::Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = vec![];
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            if line.is_empty() && !items.is_empty() {
+                break;
+            }
+            match parse::number(&line, &line) {
+                Ok(calories) => items.push(calories),
+                Err(err) => {
+                    // the error only knows the line, point it at the line in the whole inventory
+                    return Some(Err(ReadError::Parse(ParseError {
+                        line: self.line,
+                        ..err
+                    })));
+                }
+            }
+        }
+        (!items.is_empty()).then_some(Ok(Elf { items }))
+    }
+}
+
+/// The `k` most calories the elves in `reader` carry, most first, without keeping every elf.
+pub fn top_calories(reader: impl BufRead, k: usize) -> Result<Vec<usize>, ReadError> {
+    let mut err = None;
+    let top = top(
+        Elves::new(reader).map_while(|elf| match elf {
+            Ok(elf) => Some(elf.calories()),
+            Err(e) => {
+                err = Some(e);
+                None
+            }
+        }),
+        k,
+    );
+    err.map_or(Ok(top), Err)
+}
+
+/// Stats of the elves in `reader`, keeping the calories and item count of each elf but not their
+/// items. `None` without elves.
+pub fn read_stats(reader: impl BufRead) -> Result<Option<Stats>, ReadError> {
+    let elves = Elves::new(reader)
+        .map(|elf| elf.map(|elf| (elf.calories(), elf.items.len())))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Stats::new(elves))
+}
+
+/// The `k` largest of `calories`, largest first, keeping only `k` at a time.
+fn top(calories: impl Iterator<Item = usize>, k: usize) -> Vec<usize> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for calories in calories {
+        heap.push(Reverse(calories));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(calories)| calories)
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    type Parsed<'a> = Inventory;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_inventory(input)
+    }
+
+    fn part_a(inventory: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(inventory))
+    }
+
+    fn part_b(inventory: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(inventory))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(&parse_inventory(input)?))
+}
+
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(&parse_inventory(input)?))
+}
+
+fn part_a(inventory: &Inventory) -> usize {
+    inventory.top(1).iter().sum()
+}
+
+fn part_b(inventory: &Inventory) -> usize {
+    inventory.top(3).iter().sum()
+}
+
+fn parse_inventory(input: &str) -> Result<Inventory, ParseError> {
+    let elves = input
+        .split("\n\n")
+        .map(|elf| {
+            let items = elf
+                .lines()
+                .map(|line| parse::number(input, line))
+                .collect::<Result<_, _>>()?;
+            Ok(Elf { items })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Inventory { elves })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    const INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day01::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(24000));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(45000));
+        println!("{}", solution_b(&input).unwrap());
+
+        assert_eq!(
+            top_calories(TEST_INPUT.as_bytes(), 3).unwrap(),
+            vec![24000, 11000, 10000]
+        );
+        assert_eq!(
+            top_calories(input.as_bytes(), 3)
+                .unwrap()
+                .iter()
+                .sum::<usize>(),
+            solution_b(&input).unwrap()
+        );
+        let stats = read_stats(TEST_INPUT.as_bytes()).unwrap().unwrap();
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(stats.item_counts, vec![3, 1, 2, 3, 1]);
+        assert_eq!(Some(stats), parse_inventory(TEST_INPUT).unwrap().stats());
+        match top_calories("1000\n\n20x0\n".as_bytes(), 1) {
+            Err(ReadError::Parse(err)) => assert_eq!((err.line, err.column), (3, 1)),
+            result => panic!("expected a parse error, got {result:?}"),
+        }
+    }
+}
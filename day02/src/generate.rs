@@ -0,0 +1,19 @@
+//! Rounds of the strategy guide, `size` counts them.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day02;
+
+impl Generate for Day02 {
+    const SIZE: usize = 2500;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let opponent = ["A", "B", "C"][rng.gen_range(0..3)];
+                let mine = ["X", "Y", "Z"][rng.gen_range(0..3)];
+                format!("{opponent} {mine}\n")
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,332 @@
+mod generate;
+
+use std::{fmt::Display, marker::PhantomData};
+
+use solver::{ParseError, Solver};
+
+/// A move of a game where the moves form a cycle and each beats the half of the others before
+/// it, like rock, paper, scissors.
+pub trait Move: Copy + Eq + 'static {
+    /// Every move in the order of the cycle, an odd number of them so different moves never draw.
+    const ALL: &'static [Self];
+
+    /// How many moves there are, failing to compile for a game with an even number of them.
+    const COUNT: usize = {
+        assert!(
+            Self::ALL.len() % 2 == 1,
+            "a game needs an odd number of moves"
+        );
+        Self::ALL.len()
+    };
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|other| *other == self).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move for Shape {
+    const ALL: &'static [Self] = &[Shape::Rock, Shape::Paper, Shape::Scissors];
+}
+
+/// Rock, paper, scissors, lizard, Spock, each beats the two before it in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rpsls {
+    Rock,
+    Spock,
+    Paper,
+    Lizard,
+    Scissors,
+}
+
+impl Move for Rpsls {
+    const ALL: &'static [Self] = &[
+        Rpsls::Rock,
+        Rpsls::Spock,
+        Rpsls::Paper,
+        Rpsls::Lizard,
+        Rpsls::Scissors,
+    ];
+}
+
+/// How a round ends for the one playing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+/// How `mine` does against `theirs`.
+pub fn outcome<M: Move>(mine: M, theirs: M) -> Outcome {
+    let moves = M::COUNT;
+    match (mine.index() + moves - theirs.index()) % moves {
+        0 => Outcome::Draw,
+        ahead if ahead <= moves / 2 => Outcome::Win,
+        _ => Outcome::Lose,
+    }
+}
+
+/// A move that ends in `outcome` against `theirs`, the next or previous one in the cycle.
+pub fn respond<M: Move>(theirs: M, outcome: Outcome) -> M {
+    let moves = M::COUNT;
+    match outcome {
+        Outcome::Lose => M::ALL[(theirs.index() + moves - 1) % moves],
+        Outcome::Draw => theirs,
+        Outcome::Win => M::ALL[(theirs.index() + 1) % moves],
+    }
+}
+
+/// Points for a round of game `M`, for the move played and for how it ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scores<M> {
+    /// By the move's place in [`Move::ALL`], so one for each move of `M`.
+    moves: Vec<u32>,
+    /// By the outcome's place in [`Outcome::ALL`].
+    pub outcomes: [u32; 3],
+    game: PhantomData<M>,
+}
+
+impl<M: Move> Scores<M> {
+    pub fn new(moves: impl Fn(M) -> u32, outcomes: [u32; 3]) -> Self {
+        Self {
+            moves: M::ALL.iter().map(|m| moves(*m)).collect(),
+            outcomes,
+            game: PhantomData,
+        }
+    }
+
+    /// The puzzle's scoring: 1 for the first move and one more for each after it, 0 for a loss, 3
+    /// for a draw and 6 for a win.
+    pub fn for_game() -> Self {
+        Self::new(|m| m.index() as u32 + 1, [0, 3, 6])
+    }
+
+    pub fn score(&self, mine: M, theirs: M) -> u32 {
+        let outcome = outcome(mine, theirs);
+        self.moves[mine.index()] + self.outcomes[outcome as usize]
+    }
+}
+
+impl<M: Move> Default for Scores<M> {
+    fn default() -> Self {
+        Self::for_game()
+    }
+}
+
+/// The second column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl Code {
+    pub const ALL: [Code; 3] = [Code::X, Code::Y, Code::Z];
+}
+
+/// What the second column of the strategy guide says to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// X, Y and Z are rock, paper and scissors to play.
+    Shape,
+    /// X, Y and Z are the round to lose, draw or win.
+    Outcome,
+}
+
+impl Column {
+    /// The puzzle's mapping for the column, X, Y and Z in order.
+    pub fn mapping(self) -> Mapping {
+        match self {
+            Column::Shape => Mapping::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]),
+            Column::Outcome => Mapping::Outcomes(Outcome::ALL),
+        }
+    }
+}
+
+/// What X, Y and Z stand for, by the code's place in [`Code::ALL`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mapping {
+    Shapes([Shape; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+impl Mapping {
+    /// Every way to give X, Y and Z a different shape each, then a different outcome each.
+    pub fn all() -> impl Iterator<Item = Mapping> {
+        const ORDERS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let shapes = ORDERS.map(|order| Mapping::Shapes(order.map(|i| Shape::ALL[i])));
+        let outcomes = ORDERS.map(|order| Mapping::Outcomes(order.map(|i| Outcome::ALL[i])));
+        shapes.into_iter().chain(outcomes)
+    }
+
+    /// The shape to play against `theirs` for `code`.
+    pub fn mine(self, theirs: Shape, code: Code) -> Shape {
+        match self {
+            Mapping::Shapes(shapes) => shapes[code as usize],
+            Mapping::Outcomes(outcomes) => respond(theirs, outcomes[code as usize]),
+        }
+    }
+}
+
+/// A round of the strategy guide, the opponent's shape and the code to answer it with.
+pub type Round = (Shape, Code);
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    type Parsed<'a> = Vec<Round>;
+    type Params = Scores<Shape>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(rounds: &Self::Parsed<'_>, scores: &Scores<Shape>) -> Option<impl Display> {
+        Some(total_score(rounds, Column::Shape.mapping(), scores))
+    }
+
+    fn part_b(rounds: &Self::Parsed<'_>, scores: &Scores<Shape>) -> Option<impl Display> {
+        Some(total_score(rounds, Column::Outcome.mapping(), scores))
+    }
+}
+
+pub fn solution(input: &str, column: Column) -> Result<u32, ParseError> {
+    Ok(total_score(
+        &parse(input)?,
+        column.mapping(),
+        &Scores::default(),
+    ))
+}
+
+/// Score of following the guide, reading its second column with `mapping`.
+pub fn total_score(rounds: &[Round], mapping: Mapping, scores: &Scores<Shape>) -> u32 {
+    rounds
+        .iter()
+        .map(|&(theirs, code)| scores.score(mapping.mine(theirs, code), theirs))
+        .sum()
+}
+
+/// Score of following the guide under each mapping of its second column, in the order of
+/// [`Mapping::all`], for when it isn't known what the column means.
+pub fn decode(rounds: &[Round], scores: &Scores<Shape>) -> Vec<(Mapping, u32)> {
+    Mapping::all()
+        .map(|mapping| (mapping, total_score(rounds, mapping, scores)))
+        .collect()
+}
+
+/// The mapping that scores the most, the first of them on a tie.
+pub fn best_mapping(rounds: &[Round], scores: &Scores<Shape>) -> (Mapping, u32) {
+    decode(rounds, scores)
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, score)| *score)
+        .unwrap()
+}
+
+/// The mappings that score `target`.
+pub fn mappings_scoring(rounds: &[Round], scores: &Scores<Shape>, target: u32) -> Vec<Mapping> {
+    decode(rounds, scores)
+        .into_iter()
+        .filter(|(_, score)| *score == target)
+        .map(|(mapping, _)| mapping)
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let round = line.split_once(' ').and_then(|(theirs, code)| {
+                let theirs = match theirs {
+                    "A" => Shape::Rock,
+                    "B" => Shape::Paper,
+                    "C" => Shape::Scissors,
+                    _ => return None,
+                };
+                let code = match code {
+                    "X" => Code::X,
+                    "Y" => Code::Y,
+                    "Z" => Code::Z,
+                    _ => return None,
+                };
+                Some((theirs, code))
+            });
+            round.ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    line,
+                    "expected A, B or C and X, Y or Z separated by a space",
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "A Y
+B X
+C Z";
+
+    const INPUT: &str = include_str!("inputs.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day02::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT, Column::Shape), Ok(15));
+        println!("{}", solution(&input, Column::Shape).unwrap());
+        assert_eq!(solution(TEST_INPUT, Column::Outcome), Ok(12));
+        println!("{}", solution(&input, Column::Outcome).unwrap());
+
+        assert_eq!(outcome(Rpsls::Spock, Rpsls::Scissors), Outcome::Win);
+        assert_eq!(outcome(Rpsls::Lizard, Rpsls::Spock), Outcome::Win);
+        assert_eq!(outcome(Rpsls::Rock, Rpsls::Paper), Outcome::Lose);
+        for theirs in Rpsls::ALL {
+            for wanted in Outcome::ALL {
+                assert_eq!(outcome(respond(*theirs, wanted), *theirs), wanted);
+            }
+        }
+        let scores = Scores::<Rpsls>::for_game();
+        assert_eq!(scores.score(Rpsls::Scissors, Rpsls::Lizard), 11);
+        assert_eq!(scores.score(Rpsls::Scissors, Rpsls::Scissors), 8);
+        let flat = Scores::new(|_: Rpsls| 1, [0, 1, 2]);
+        assert_eq!(flat.score(Rpsls::Lizard, Rpsls::Spock), 3);
+
+        let rounds = parse(TEST_INPUT).unwrap();
+        let scores = Scores::default();
+        assert_eq!(decode(&rounds, &scores).len(), 12);
+        assert!(mappings_scoring(&rounds, &scores, 15).contains(&Column::Shape.mapping()));
+        assert!(mappings_scoring(&rounds, &scores, 12).contains(&Column::Outcome.mapping()));
+        // the guide has a different code against each shape, so some mapping wins every round
+        assert_eq!(
+            best_mapping(&rounds, &scores),
+            (
+                Mapping::Shapes([Shape::Scissors, Shape::Paper, Shape::Rock]),
+                24
+            )
+        );
+    }
+}
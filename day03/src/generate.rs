@@ -0,0 +1,59 @@
+//! Rucksacks in groups of three, `size` counts the rucksacks and is rounded up to a whole group.
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::Day03;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generate for Day03 {
+    const SIZE: usize = 300;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1).div_ceil(3) {
+            let mut items = ITEMS.to_vec();
+            items.shuffle(rng);
+            let (badge, items) = items.split_first().unwrap();
+            // each rucksack of the group packs its own items besides the badge, so the badge is
+            // the only item all three have
+            for items in items.chunks(items.len() / 3).take(3) {
+                input.push_str(&rucksack(*badge, items, rng));
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+/// Compartments packed from two halves of `items` that only share one item, with `badge` in one.
+fn rucksack(badge: u8, items: &[u8], rng: &mut impl Rng) -> String {
+    let mut items = items.to_vec();
+    items.push(badge);
+    items.shuffle(rng);
+    let (shared, items) = items.split_first().unwrap();
+    let (left_items, right_items) = items.split_at(items.len() / 2);
+    let len = rng.gen_range(2..=16);
+    let mut compartment = |items: &[u8]| {
+        let mut compartment: Vec<u8> = (1..len).map(|_| *items.choose(rng).unwrap()).collect();
+        compartment.push(*shared);
+        compartment
+    };
+    let mut left = compartment(left_items);
+    let mut right = compartment(right_items);
+    if badge != *shared {
+        let compartment = if left_items.contains(&badge) {
+            &mut left
+        } else {
+            &mut right
+        };
+        compartment[0] = badge;
+    }
+    left.shuffle(rng);
+    right.shuffle(rng);
+    left.extend(right);
+    String::from_utf8(left).unwrap()
+}
@@ -0,0 +1,346 @@
+mod generate;
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    num::NonZeroUsize,
+};
+
+use solver::{ParseError, Solver};
+
+/// A set of items, a bit for each priority: a to z are 1 to 26 and A to Z are 27 to 52.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub fn new(items: &[u8]) -> Result<Self, NotAnItem> {
+        items
+            .iter()
+            .enumerate()
+            .try_fold(0, |bits, (index, item)| match item {
+                b'a'..=b'z' | b'A'..=b'Z' => Ok(bits | 1 << priority(*item)),
+                _ => Err(NotAnItem { index, byte: *item }),
+            })
+            .map(Self)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+    }
+
+    /// The priority of the only item, `None` for none or several.
+    fn only(self) -> Option<u32> {
+        (self.len() == 1).then(|| self.0.trailing_zeros())
+    }
+}
+
+/// The items as letters, a to z then A to Z.
+impl Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for priority in self.priorities() {
+            write!(f, "{}", item(priority) as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// A byte that isn't a letter, so not an item, and where it is among the items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotAnItem {
+    pub index: usize,
+    pub byte: u8,
+}
+
+impl Display for NotAnItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a letter at {}, got byte {:#04x}",
+            self.index, self.byte
+        )
+    }
+}
+
+impl Error for NotAnItem {}
+
+/// A rucksack by the items in each of its compartments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+}
+
+impl Rucksack {
+    /// Items in both compartments.
+    pub fn shared(&self) -> Items {
+        self.left.intersection(self.right)
+    }
+
+    pub fn items(&self) -> Items {
+        self.left.union(self.right)
+    }
+}
+
+/// The rucksacks don't share exactly one item where the puzzle expects them to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RucksackError {
+    Parse(ParseError),
+    /// The compartments of the rucksack on `line` share these items.
+    Compartments {
+        line: usize,
+        shared: Items,
+    },
+    /// The group of rucksacks from `line` on shares these items.
+    Badge {
+        line: usize,
+        shared: Items,
+    },
+    /// The last group, from `line` on, has fewer rucksacks than the others.
+    PartialGroup {
+        line: usize,
+    },
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = |shared: &Items| match shared.len() {
+            0 => "no items".to_string(),
+            _ => format!("items {shared}"),
+        };
+        match self {
+            RucksackError::Parse(err) => err.fmt(f),
+            RucksackError::Compartments { line, shared } => write!(
+                f,
+                "line {line}: expected one item in both compartments, they share {}",
+                items(shared)
+            ),
+            RucksackError::Badge { line, shared } => write!(
+                f,
+                "line {line}: expected the group to share one badge, it shares {}",
+                items(shared)
+            ),
+            RucksackError::PartialGroup { line } => {
+                write!(f, "line {line}: expected a whole group of rucksacks")
+            }
+        }
+    }
+}
+
+impl Error for RucksackError {}
+
+impl From<ParseError> for RucksackError {
+    fn from(err: ParseError) -> Self {
+        RucksackError::Parse(err)
+    }
+}
+
+pub struct Day03;
+
+pub struct Params {
+    /// Rucksacks in a group, sharing a badge, for part B.
+    pub group_size: NonZeroUsize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            group_size: NonZeroUsize::new(3).unwrap(),
+        }
+    }
+}
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    type Parsed<'a> = Vec<Rucksack>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(rucksacks: &Self::Parsed<'_>, _: &Params) -> Option<impl Display> {
+        part_a(rucksacks).ok()
+    }
+
+    fn part_b(rucksacks: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        part_b(rucksacks, params.group_size).ok()
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<u32, RucksackError> {
+    part_a(&parse(input)?)
+}
+
+pub fn solution_b(input: &str, group_size: NonZeroUsize) -> Result<u32, RucksackError> {
+    part_b(&parse(input)?, group_size)
+}
+
+/// Items in both compartments of each rucksack.
+pub fn shared_items(rucksacks: &[Rucksack]) -> Vec<Items> {
+    rucksacks.iter().map(Rucksack::shared).collect()
+}
+
+/// Items in every rucksack of each group of `group_size`, the last group can be smaller.
+pub fn group_items(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> Vec<Items> {
+    rucksacks
+        .chunks(group_size.get())
+        .map(|group| {
+            group
+                .iter()
+                .map(Rucksack::items)
+                .reduce(Items::intersection)
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn part_a(rucksacks: &[Rucksack]) -> Result<u32, RucksackError> {
+    shared_items(rucksacks)
+        .into_iter()
+        .enumerate()
+        .map(|(i, shared)| {
+            shared.only().ok_or(RucksackError::Compartments {
+                line: i + 1,
+                shared,
+            })
+        })
+        .sum()
+}
+
+fn part_b(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> Result<u32, RucksackError> {
+    let size = group_size.get();
+    if !rucksacks.len().is_multiple_of(size) {
+        return Err(RucksackError::PartialGroup {
+            line: rucksacks.len() / size * size + 1,
+        });
+    }
+    group_items(rucksacks, group_size)
+        .into_iter()
+        .enumerate()
+        .map(|(i, shared)| {
+            shared.only().ok_or(RucksackError::Badge {
+                line: i * size + 1,
+                shared,
+            })
+        })
+        .sum()
+}
+
+/// Checks every rucksack is an even number of letters, so each has two compartments.
+fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let half = line.len() / 2;
+            // every byte before a bad one is a letter, so it starts a character
+            let items = |from: usize, to: usize| {
+                Items::new(&line.as_bytes()[from..to]).map_err(|err| {
+                    ParseError::new(input, &line[from + err.index..], "expected a letter")
+                })
+            };
+            let (left, right) = (items(0, half)?, items(half, line.len())?);
+            if line.len() % 2 != 0 {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "expected an even number of items",
+                ));
+            }
+            Ok(Rucksack { left, right })
+        })
+        .collect()
+}
+
+fn priority(item: u8) -> u32 {
+    match item {
+        b'a'..=b'z' => (item - b'a' + 1).into(),
+        _ => (item - b'A' + 27).into(),
+    }
+}
+
+fn item(priority: u32) -> u8 {
+    match priority {
+        1..=26 => b'a' + priority as u8 - 1,
+        _ => b'A' + priority as u8 - 27,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    const INPUT: &str = include_str!("inputs.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day03::DAY, INPUT);
+        let size = |size| NonZeroUsize::new(size).unwrap();
+        assert_eq!(solution_a(TEST_INPUT), Ok(157));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT, size(3)), Ok(70));
+        println!("{}", solution_b(&input, size(3)).unwrap());
+
+        let rucksacks = parse(TEST_INPUT).unwrap();
+        assert_eq!(shared_items(&rucksacks)[0].to_string(), "p");
+        assert_eq!(group_items(&rucksacks, size(2))[0].to_string(), "frsFM");
+        assert_eq!(group_items(&rucksacks, size(6))[0].to_string(), "");
+        assert_eq!(
+            solution_a("abcd"),
+            Err(RucksackError::Compartments {
+                line: 1,
+                shared: Items::default()
+            })
+        );
+        assert_eq!(
+            solution_b(TEST_INPUT, size(4)),
+            Err(RucksackError::PartialGroup { line: 5 })
+        );
+        assert_eq!(
+            Items::new(b"aZ1"),
+            Err(NotAnItem {
+                index: 2,
+                byte: b'1'
+            })
+        );
+        for (line, column, message) in [
+            ("abc1", 4, "expected a letter"),
+            ("a\u{e9}b", 2, "expected a letter"),
+            ("abc", 1, "expected an even number of items"),
+        ] {
+            let err = parse(&format!("ab\n{line}")).unwrap_err();
+            assert_eq!(
+                (err.line, err.column, err.message.as_str()),
+                (2, column, message)
+            );
+        }
+        assert!(matches!(
+            solution_b(TEST_INPUT, size(2)),
+            Err(RucksackError::Badge { line: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,19 @@
+//! Pairs of section assignments, `size` counts the pairs.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day04;
+
+impl Generate for Day04 {
+    const SIZE: usize = 1000;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            format!("{start}-{}", rng.gen_range(start..=99))
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
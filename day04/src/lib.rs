@@ -0,0 +1,200 @@
+mod generate;
+
+use std::fmt::{self, Display};
+
+pub use day04_nom::Ranges;
+use day04_nom::{contains, part_a, part_b};
+use interval::{Interval, IntervalSet};
+use solver::{ParseError, Solver};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    type Parsed<'a> = Vec<Ranges>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        day04_nom::parse(input)
+    }
+
+    fn part_a(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(pairs))
+    }
+
+    fn part_b(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(pairs))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(&day04_nom::parse(input)?))
+}
+
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(&day04_nom::parse(input)?))
+}
+
+/// How the ranges of one pair relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    /// 1-based line of the pair in the input.
+    pub line: usize,
+    pub ranges: Ranges,
+    /// One range has all the sections of the other.
+    pub contains: bool,
+    /// The sections both elves are assigned.
+    pub overlap: Option<Interval<u32>>,
+}
+
+/// Every pair and the sections all the elves cover between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub pairs: Vec<Pair>,
+    /// How many elves are assigned each run of sections, in order and leaving out the sections no
+    /// elf is assigned.
+    pub coverage: Vec<(Interval<u32>, usize)>,
+}
+
+impl Report {
+    pub fn new(pairs: &[Ranges]) -> Self {
+        let report = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, &(a, b))| Pair {
+                line: i + 1,
+                ranges: (a, b),
+                contains: contains((a, b)),
+                overlap: a.intersection(b),
+            })
+            .collect();
+
+        // an elf joins at the start of its range and leaves after the end, ends go past u32
+        let mut changes: Vec<(u64, isize)> = pairs
+            .iter()
+            .flat_map(|(a, b)| [a, b])
+            .flat_map(|range| [(range.start().into(), 1), (u64::from(range.end()) + 1, -1)])
+            .collect();
+        changes.sort_unstable();
+        let mut coverage: Vec<(Interval<u32>, usize)> = vec![];
+        let mut elves = 0;
+        for (i, (at, change)) in changes.iter().enumerate() {
+            elves += change;
+            let next = changes.get(i + 1).map(|(next, _)| *next);
+            if elves == 0 || next.is_none_or(|next| next == *at) {
+                continue;
+            }
+            // at and next are sections or one past one, so the run between them is in u32
+            let (start, end) = (*at as u32, (next.unwrap() - 1) as u32);
+            match coverage.last_mut() {
+                // one elf takes over right where another leaves off
+                Some((run, last)) if *last == elves as usize && run.end() + 1 == start => {
+                    *run = Interval::new(run.start(), end).unwrap();
+                }
+                _ => coverage.push((Interval::new(start, end).unwrap(), elves as usize)),
+            }
+        }
+
+        Self {
+            pairs: report,
+            coverage,
+        }
+    }
+
+    /// Pairs where one range has all the sections of the other.
+    pub fn containing(&self) -> impl Iterator<Item = &Pair> {
+        self.pairs.iter().filter(|pair| pair.contains)
+    }
+
+    /// Pairs that share any sections.
+    pub fn overlapping(&self) -> impl Iterator<Item = &Pair> {
+        self.pairs.iter().filter(|pair| pair.overlap.is_some())
+    }
+
+    /// The most elves assigned any one section, and the sections they're assigned. `None` without
+    /// pairs.
+    pub fn most_covered(&self) -> Option<(usize, IntervalSet<u32>)> {
+        let most = self.coverage.iter().map(|(_, elves)| *elves).max()?;
+        let sections = self
+            .coverage
+            .iter()
+            .filter(|(_, elves)| *elves == most)
+            .map(|(run, _)| *run)
+            .collect();
+        Some((most, sections))
+    }
+}
+
+/// A line per pair, e.g. `2-8,3-7: contains, overlap 3-7`, then the most covered sections.
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pair in &self.pairs {
+            let (a, b) = pair.ranges;
+            write!(f, "{a},{b}:")?;
+            if pair.contains {
+                write!(f, " contains,")?;
+            }
+            match pair.overlap {
+                Some(overlap) => writeln!(f, " overlap {overlap}")?,
+                None => writeln!(f, " no overlap")?,
+            }
+        }
+        if let Some((elves, sections)) = self.most_covered() {
+            let sections: Vec<_> = sections
+                .intervals()
+                .iter()
+                .map(|run| run.to_string())
+                .collect();
+            writeln!(f, "{elves} elves cover {}", sections.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    const INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day04::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(2));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(4));
+        println!("{}", solution_b(&input).unwrap());
+
+        let report = Report::new(&day04_nom::parse(TEST_INPUT).unwrap());
+        assert_eq!(
+            report
+                .containing()
+                .map(|pair| pair.line)
+                .collect::<Vec<_>>(),
+            vec![4, 5]
+        );
+        assert_eq!(
+            report
+                .overlapping()
+                .map(|pair| pair.overlap)
+                .collect::<Vec<_>>(),
+            [(7, 7), (3, 7), (6, 6), (4, 6)].map(|(start, end)| Interval::new(start, end))
+        );
+        // section 6 is in eight of the twelve ranges
+        assert_eq!(
+            report.most_covered(),
+            Some((8, IntervalSet::from_iter(Interval::new(6, 6))))
+        );
+        assert_eq!(report.to_string().lines().last(), Some("8 elves cover 6-6"));
+        let report = Report::new(&day04_nom::parse("1-3,4-6").unwrap());
+        assert_eq!(report.coverage, vec![(Interval::new(1, 6).unwrap(), 1)]);
+    }
+}
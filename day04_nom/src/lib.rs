@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use interval::Interval;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, newline},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+use solver::{parse, ParseError, Solver};
+
+/// The sections each elf of a pair is assigned.
+pub type Ranges = (Interval<u32>, Interval<u32>);
+
+fn parse_range(input: &str) -> IResult<&str, Interval<u32>> {
+    let (rest, (start, end)) = separated_pair(complete::u32, tag("-"), complete::u32)(input)?;
+    match Interval::new(start, end) {
+        Some(range) => Ok((rest, range)),
+        // fail outright rather than end the list of pairs on the line before
+        None => Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify))),
+    }
+}
+
+fn parse_line(line: &str) -> IResult<&str, Ranges> {
+    separated_pair(parse_range, tag(","), parse_range)(line)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Ranges>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Ranges>, ParseError> {
+    match parse_input(input) {
+        Err(nom::Err::Failure(err)) if err.code == ErrorKind::Verify => Err(ParseError::new(
+            input,
+            err.input,
+            "expected a range from low to high",
+        )),
+        result => parse::finish(input, result),
+    }
+}
+
+pub struct Day04Nom;
+
+impl Solver for Day04Nom {
+    const DAY: u8 = 4;
+    type Parsed<'a> = Vec<Ranges>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(pairs))
+    }
+
+    fn part_b(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(pairs))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(&parse(input)?))
+}
+
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(&parse(input)?))
+}
+
+/// Pairs where one range has all the sections of the other.
+pub fn part_a(pairs: &[Ranges]) -> usize {
+    pairs.iter().filter(|pair| contains(**pair)).count()
+}
+
+/// Pairs that share any sections.
+pub fn part_b(pairs: &[Ranges]) -> usize {
+    pairs.iter().filter(|(a, b)| a.overlaps(*b)).count()
+}
+
+/// Whether one range of the pair has all the sections of the other.
+pub fn contains((a, b): Ranges) -> bool {
+    a.covers(b) || b.covers(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    const INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day04Nom::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(2));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(4));
+        println!("{}", solution_b(&input).unwrap());
+        let err = solution_a("2-4,6-8\n5-3,1-2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,44 @@
+//! Nine stacks of crates and the moves of the crane, `size` counts the moves.
+
+use generator::{rand::Rng, Generate};
+
+use crate::{draw, Day05};
+
+const STACKS: usize = 9;
+
+impl Generate for Day05 {
+    const SIZE: usize = 503;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut stacks: Vec<Vec<char>> = (0..STACKS)
+            .map(|_| {
+                (0..rng.gen_range(2..=8))
+                    .map(|_| rng.gen_range('A'..='Z'))
+                    .collect()
+            })
+            .collect();
+        let drawing = draw(&stacks);
+
+        // a move leaves at least a crate behind, so every stack has a crate on top at the end
+        let mut moves = String::new();
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.gen_range(0..STACKS);
+                if stacks[from].len() > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..STACKS)) % STACKS;
+            let quantity = rng.gen_range(1..stacks[from].len());
+            let len = stacks[from].len();
+            let crates: Vec<char> = stacks[from].drain(len - quantity..).collect();
+            stacks[to].extend(crates);
+            moves.push_str(&format!(
+                "move {quantity} from {} to {}\n",
+                from + 1,
+                to + 1
+            ));
+        }
+        format!("{drawing}\n{moves}")
+    }
+}
@@ -0,0 +1,647 @@
+mod generate;
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, newline},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use solver::{parse, ParseError, Solver};
+
+/// The crates of each stack, bottom first.
+pub type Stacks = Vec<Vec<char>>;
+
+/// A model of crane, how it moves crates from one stack onto another.
+pub trait Crane {
+    /// Moves the top `quantity` crates of `from` onto `to`, `from` has at least that many.
+    fn shift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize);
+
+    /// Undoes [`Crane::shift`], putting the top `quantity` crates of `to` back on `from` as they
+    /// were, `to` has at least that many.
+    fn unshift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize);
+}
+
+/// Moves one crate at a time, so the crates of a move land in reverse order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn shift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+        to.extend(from.drain(from.len() - quantity..).rev());
+    }
+
+    fn unshift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+        self.shift(to, from, quantity);
+    }
+}
+
+/// Moves all the crates of a move at once, keeping their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn shift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+        to.extend(from.drain(from.len() - quantity..));
+    }
+
+    fn unshift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+        self.shift(to, from, quantity);
+    }
+}
+
+/// A move the warehouse can't make, by the 1-based number of the move and with stacks 1-based
+/// like the moves number them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// The move names `stack`, but the warehouse has `stacks`.
+    NoStack {
+        cmd: usize,
+        stack: usize,
+        stacks: usize,
+    },
+    /// The move wants more crates than `stack` has.
+    Short {
+        cmd: usize,
+        stack: usize,
+        wanted: usize,
+        had: usize,
+    },
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoStack { cmd, stack, stacks } => {
+                write!(f, "move {cmd}: expected stacks 1 to {stacks}, got {stack}")
+            }
+            MoveError::Short {
+                cmd,
+                stack,
+                wanted,
+                had,
+            } => write!(
+                f,
+                "move {cmd} takes {wanted} crates from stack {stack}, it has {had}"
+            ),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// Rearranging the crates failed, either reading the input or a move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrateError {
+    Parse(ParseError),
+    Move(MoveError),
+}
+
+impl Display for CrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrateError::Parse(err) => err.fmt(f),
+            CrateError::Move(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for CrateError {}
+
+impl From<ParseError> for CrateError {
+    fn from(err: ParseError) -> Self {
+        CrateError::Parse(err)
+    }
+}
+
+impl From<MoveError> for CrateError {
+    fn from(err: MoveError) -> Self {
+        CrateError::Move(err)
+    }
+}
+
+/// The stacks of crates and how many moves have been made on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    stacks: Stacks,
+    moves: usize,
+}
+
+impl Warehouse {
+    pub fn new(stacks: Stacks) -> Self {
+        Self { stacks, moves: 0 }
+    }
+
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    /// Moves crates with `crane` like `cmd` says, leaving the stacks be when it can't. Moving
+    /// crates onto the stack they're on leaves them be too.
+    pub fn apply(&mut self, crane: &dyn Crane, cmd: &Cmd) -> Result<(), MoveError> {
+        let number = self.moves + 1;
+        if let Some(stack) = [cmd.from, cmd.to]
+            .into_iter()
+            .find(|stack| *stack >= self.stacks.len())
+        {
+            return Err(MoveError::NoStack {
+                cmd: number,
+                // stack 0 of the input wrapped around when it was made 0-based
+                stack: stack.wrapping_add(1),
+                stacks: self.stacks.len(),
+            });
+        }
+        let had = self.stacks[cmd.from].len();
+        if had < cmd.quantity {
+            return Err(MoveError::Short {
+                cmd: number,
+                stack: cmd.from + 1,
+                wanted: cmd.quantity,
+                had,
+            });
+        }
+        if cmd.from != cmd.to {
+            let (from, to) = pair_mut(&mut self.stacks, cmd.from, cmd.to);
+            crane.shift(from, to, cmd.quantity);
+        }
+        self.moves += 1;
+        Ok(())
+    }
+
+    /// Applies the moves in order, stopping at the first one that fails.
+    pub fn apply_all(&mut self, crane: &dyn Crane, cmds: &[Cmd]) -> Result<(), MoveError> {
+        cmds.iter().try_for_each(|cmd| self.apply(crane, cmd))
+    }
+
+    /// The crate on top of each stack, a space for an empty stack.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect()
+    }
+
+    /// The warehouse after each of `cmds`, ending after the first move that fails.
+    pub fn replay<'a>(self, crane: &'a dyn Crane, cmds: &'a [Cmd]) -> Replay<'a> {
+        Replay {
+            warehouse: Some(self),
+            crane,
+            cmds: cmds.iter(),
+        }
+    }
+}
+
+/// The stacks drawn like the puzzle input.
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&draw(&self.stacks))
+    }
+}
+
+/// The states of a [`Warehouse`] as a crane makes moves, see [`Warehouse::replay`].
+pub struct Replay<'a> {
+    /// `None` once a move failed.
+    warehouse: Option<Warehouse>,
+    crane: &'a dyn Crane,
+    cmds: std::slice::Iter<'a, Cmd>,
+}
+
+impl Iterator for Replay<'_> {
+    type Item = Result<Warehouse, MoveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let warehouse = self.warehouse.as_mut()?;
+        let cmd = self.cmds.next()?;
+        match warehouse.apply(self.crane, cmd) {
+            Ok(()) => Some(Ok(warehouse.clone())),
+            Err(err) => {
+                self.warehouse = None;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// The stacks like the puzzle input draws them, a row per level with the stack numbers under
+/// them, which [`Day05`] parses back for up to nine stacks.
+pub fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing.push_str(&row.join(" "));
+        drawing.push('\n');
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+    drawing.push_str(&numbers.join(" "));
+    drawing.push('\n');
+    drawing
+}
+
+/// Both stacks mutably, `a` and `b` have to differ.
+fn pair_mut(stacks: &mut [Vec<char>], a: usize, b: usize) -> (&mut Vec<char>, &mut Vec<char>) {
+    if a < b {
+        let (left, right) = stacks.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = stacks.split_at_mut(a);
+        (&mut right[0], &mut left[b])
+    }
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    type Parsed<'a> = (Stacks, Vec<Cmd>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a((stacks, cmds): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        rearrange(stacks, cmds, &CrateMover9000).ok()
+    }
+
+    fn part_b((stacks, cmds): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        rearrange(stacks, cmds, &CrateMover9001).ok()
+    }
+}
+
+pub fn solution(input: &str, crane: &dyn Crane) -> Result<String, CrateError> {
+    let (stacks, cmds) = parse(input)?;
+    Ok(rearrange(&stacks, &cmds, crane)?)
+}
+
+/// The crates on top after `crane` makes the moves.
+fn rearrange(stacks: &Stacks, cmds: &[Cmd], crane: &dyn Crane) -> Result<String, MoveError> {
+    let mut warehouse = Warehouse::new(stacks.clone());
+    warehouse.apply_all(crane, cmds)?;
+    Ok(warehouse.tops())
+}
+
+/// Planning stacks that end with given crates on top failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    /// The wanted top of 1-based `stack` is neither a letter nor a space.
+    Top { stack: usize, top: char },
+    /// Move `cmd`, 1-based, names a stack beyond the `stacks` there are.
+    Stacks { cmd: usize, stacks: usize },
+    /// Undoing move `cmd` takes crates off 1-based `stack`, but it has to end up empty.
+    Empty { cmd: usize, stack: usize },
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::Top { stack, top } => {
+                write!(
+                    f,
+                    "expected a crate or a space on stack {stack}, got {top:?}"
+                )
+            }
+            PlanError::Stacks { cmd, stacks } => {
+                write!(f, "move {cmd}: expected stacks 1 to {stacks}")
+            }
+            PlanError::Empty { cmd, stack } => write!(
+                f,
+                "move {cmd} takes crates from stack {stack}, which has to end up empty"
+            ),
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+/// Stacks that end with the crates of `tops` on top after `crane` makes the moves, a space in
+/// `tops` for an empty stack. Undoes the moves from the end, adding crates under the stacks that
+/// end with one whenever a move needs more, so it has as few crates as any stacks that work. The
+/// crates the moves don't pin down are `filler`.
+pub fn plan(
+    cmds: &[Cmd],
+    tops: &str,
+    crane: &dyn Crane,
+    filler: char,
+) -> Result<Stacks, PlanError> {
+    let mut stacks = tops
+        .chars()
+        .enumerate()
+        .map(|(i, top)| match top {
+            ' ' => Ok(vec![]),
+            _ if top.is_ascii_alphabetic() => Ok(vec![top]),
+            _ => Err(PlanError::Top { stack: i + 1, top }),
+        })
+        .collect::<Result<Stacks, _>>()?;
+    let open: Vec<bool> = stacks.iter().map(|stack| !stack.is_empty()).collect();
+    for (i, cmd) in cmds.iter().enumerate().rev() {
+        if cmd.from >= stacks.len() || cmd.to >= stacks.len() {
+            return Err(PlanError::Stacks {
+                cmd: i + 1,
+                stacks: stacks.len(),
+            });
+        }
+        let missing = cmd.quantity.saturating_sub(stacks[cmd.to].len());
+        if missing > 0 {
+            // later moves never reach below the crates there are, so these stay at the bottom
+            if !open[cmd.to] {
+                return Err(PlanError::Empty {
+                    cmd: i + 1,
+                    stack: cmd.to + 1,
+                });
+            }
+            stacks[cmd.to].splice(0..0, vec![filler; missing]);
+        }
+        if cmd.from != cmd.to {
+            let (from, to) = pair_mut(&mut stacks, cmd.from, cmd.to);
+            crane.unshift(from, to, cmd.quantity);
+        }
+    }
+    Ok(stacks)
+}
+
+/// A move of the crane, with stacks counted from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cmd {
+    pub quantity: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+fn parse(input: &str) -> Result<(Stacks, Vec<Cmd>), ParseError> {
+    let Some((a, b)) = input.split_once("\n\n") else {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a blank line between the stacks and the moves",
+        ));
+    };
+    let stacks = parse_stacks(input, a)?;
+    let cmds = parse::finish(input, parse_cmds(b))?;
+    for (line, cmd) in b.lines().zip(&cmds) {
+        if cmd.from >= stacks.len() || cmd.to >= stacks.len() {
+            return Err(ParseError::new(
+                input,
+                line,
+                format!("expected stacks 1 to {}", stacks.len()),
+            ));
+        }
+    }
+    Ok((stacks, cmds))
+}
+
+fn parse_stacks(input: &str, a: &str) -> Result<Stacks, ParseError> {
+    let rev: Vec<&str> = a.lines().rev().collect();
+    let positions: Vec<usize> = rev
+        .first()
+        .map(|line| {
+            line.chars()
+                .enumerate()
+                .filter_map(|(i, c)| c.is_numeric().then_some(i))
+                .collect()
+        })
+        .unwrap_or_default();
+    if positions.is_empty() {
+        return Err(ParseError::new(
+            input,
+            rev.first().unwrap_or(&a),
+            "expected a line of stack numbers",
+        ));
+    }
+    let stacks = positions
+        .iter()
+        .map(|n| {
+            rev.iter()
+                .skip(1)
+                // trailing spaces of a line may be trimmed
+                .filter_map(|line| line.chars().nth(*n))
+                .filter(|c| c.is_ascii_alphabetic())
+                .collect()
+        })
+        .collect();
+    Ok(stacks)
+}
+
+/// Just the moves, without the drawing of the stacks before them.
+pub fn parse_moves(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    parse::finish(input, parse_cmds(input))
+}
+
+fn parse_cmds(input: &str) -> IResult<&str, Vec<Cmd>> {
+    separated_list1(newline, parse_cmd)(input)
+}
+
+fn parse_cmd(line: &str) -> IResult<&str, Cmd> {
+    let (remainder, (_, quantity, _, from, _, to)) = parse_line(line)?;
+    Ok((
+        remainder,
+        Cmd {
+            // stack 0 wraps around and is rejected with the other stacks out of range
+            from: (from as usize).wrapping_sub(1),
+            to: (to as usize).wrapping_sub(1),
+            quantity: quantity as usize,
+        },
+    ))
+}
+
+fn parse_line(line: &str) -> IResult<&str, (&str, u32, &str, u32, &str, u32)> {
+    tuple((
+        tag("move "),
+        complete::u32,
+        tag(" from "),
+        complete::u32,
+        tag(" to "),
+        complete::u32,
+    ))(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solver::input;
+
+    const TEST_INPUT: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    const INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn it_works() {
+        let input = input::or_embedded(Day05::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT, &CrateMover9000).as_deref(), Ok("CMZ"));
+        println!("{}", solution(&input, &CrateMover9000).unwrap());
+        assert_eq!(solution(TEST_INPUT, &CrateMover9001).as_deref(), Ok("MCD"));
+        println!("{}", solution(&input, &CrateMover9001).unwrap());
+
+        // a crane that lifts up to two crates at once, keeping their order
+        struct Pairs;
+        impl Pairs {
+            fn lifts(quantity: usize) -> impl DoubleEndedIterator<Item = usize> {
+                (0..quantity)
+                    .step_by(2)
+                    .map(move |lift| (quantity - lift).min(2))
+            }
+        }
+        impl Crane for Pairs {
+            fn shift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+                for lift in Pairs::lifts(quantity) {
+                    CrateMover9001.shift(from, to, lift);
+                }
+            }
+            fn unshift(&self, from: &mut Vec<char>, to: &mut Vec<char>, quantity: usize) {
+                for lift in Pairs::lifts(quantity).rev() {
+                    CrateMover9001.unshift(from, to, lift);
+                }
+            }
+        }
+        assert_eq!(solution(TEST_INPUT, &Pairs).as_deref(), Ok("MCZ"));
+
+        assert_eq!(
+            solution("[A]\n 1   2 \n\nmove 2 from 1 to 2", &CrateMover9001),
+            Err(CrateError::Move(MoveError::Short {
+                cmd: 1,
+                stack: 1,
+                wanted: 2,
+                had: 1
+            }))
+        );
+        assert_eq!(
+            solution("[A]\n 1   2 \n\nmove 1 from 1 to 2", &CrateMover9000).as_deref(),
+            Ok(" A")
+        );
+        assert_eq!(
+            solution("[A]\n 1   2 \n\nmove 1 from 1 to 1", &CrateMover9000).as_deref(),
+            Ok("A ")
+        );
+        let mut warehouse = Warehouse::new(vec![vec!['A'], vec![]]);
+        let cmd = |quantity, from, to| Cmd { quantity, from, to };
+        assert_eq!(
+            warehouse.apply(&CrateMover9001, &cmd(1, 0, 2)),
+            Err(MoveError::NoStack {
+                cmd: 1,
+                stack: 3,
+                stacks: 2
+            })
+        );
+        assert!(matches!(
+            warehouse.apply(&CrateMover9001, &cmd(1, usize::MAX, 0)),
+            Err(MoveError::NoStack { stack: 0, .. })
+        ));
+        assert!(matches!(
+            warehouse.apply(&CrateMover9001, &cmd(1, 1, 1)),
+            Err(MoveError::Short { stack: 2, .. })
+        ));
+        assert_eq!(warehouse.apply(&CrateMover9001, &cmd(1, 0, 0)), Ok(()));
+        assert_eq!((warehouse.tops().as_str(), warehouse.moves()), ("A ", 1));
+
+        let (stacks, cmds) = parse(TEST_INPUT).unwrap();
+        let (drawing, _) = TEST_INPUT.split_once("\n\n").unwrap();
+        assert_eq!(draw(&stacks), format!("{drawing}\n"));
+        let states: Vec<_> = Warehouse::new(stacks.clone())
+            .replay(&CrateMover9000, &cmds)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(states.len(), cmds.len());
+        assert_eq!(
+            states[1].to_string(),
+            "        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3 \n"
+        );
+        assert_eq!(states[3].moves(), 4);
+        assert_eq!(states[3].tops(), "CMZ");
+        // every state draws to input that parses back to it
+        for state in &states {
+            let (stacks, _) =
+                parse(&format!("{state}\n{}", TEST_INPUT.lines().last().unwrap())).unwrap();
+            assert_eq!(stacks, state.stacks());
+        }
+        let mut replay = Warehouse::new(stacks).replay(
+            &CrateMover9001,
+            &[
+                Cmd {
+                    quantity: 2,
+                    from: 2,
+                    to: 0,
+                },
+                Cmd {
+                    quantity: 1,
+                    from: 1,
+                    to: 0,
+                },
+            ],
+        );
+        assert!(matches!(
+            replay.next(),
+            Some(Err(MoveError::Short { cmd: 1, .. }))
+        ));
+        assert!(replay.next().is_none());
+
+        // planned stacks end with the wanted crates on top, whichever crane moves them
+        let real = parse(&input).unwrap().1;
+        let cranes: [&dyn Crane; 3] = [&CrateMover9000, &CrateMover9001, &Pairs];
+        for (cmds, tops) in [
+            (&cmds, "CMZ"),
+            (&cmds, "MCD"),
+            (&cmds, "QQQ"),
+            (&real, "QWERTYUIO"),
+        ] {
+            for crane in cranes {
+                let mut warehouse = Warehouse::new(plan(cmds, tops, crane, 'X').unwrap());
+                warehouse.apply_all(crane, cmds).unwrap();
+                assert_eq!(warehouse.tops(), tops);
+            }
+        }
+        // one crate fewer than the example, which never needs the crate at the bottom of stack 3
+        let planned = plan(&cmds, "CMZ", &CrateMover9000, 'X').unwrap();
+        assert_eq!(planned, vec![vec!['Z', 'X'], vec!['M', 'C', 'X'], vec![]]);
+        let cmds = parse_moves("move 1 from 1 to 2\nmove 1 from 2 to 3").unwrap();
+        assert_eq!(
+            plan(&cmds, "A B", &CrateMover9000, 'X'),
+            Ok(vec![vec!['A', 'B'], vec![], vec![]])
+        );
+        assert_eq!(
+            plan(&cmds, "A  ", &CrateMover9000, 'X'),
+            Err(PlanError::Empty { cmd: 2, stack: 3 })
+        );
+        assert_eq!(
+            plan(&cmds, "AB", &CrateMover9000, 'X'),
+            Err(PlanError::Stacks { cmd: 2, stacks: 2 })
+        );
+        assert_eq!(
+            plan(&cmds, "AB1", &CrateMover9000, 'X'),
+            Err(PlanError::Top { stack: 3, top: '1' })
+        );
+        assert_eq!(
+            plan(
+                &parse_moves("move 2 from 1 to 1").unwrap(),
+                "A",
+                &CrateMover9000,
+                'X'
+            ),
+            Ok(vec![vec!['X', 'A']])
+        );
+        assert_eq!(
+            plan(&cmds, " AB", &CrateMover9000, 'X'),
+            Ok(vec![vec!['B'], vec!['A'], vec![]])
+        );
+    }
+}
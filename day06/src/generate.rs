@@ -0,0 +1,29 @@
+//! A datastream, `size` counts its characters.
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::Day06;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+impl Generate for Day06 {
+    const SIZE: usize = 4096;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        // there's no marker in a stream of three letters until one is put in
+        let letters: Vec<u8> = LETTERS.choose_multiple(rng, 3).copied().collect();
+        let mut stream: Vec<u8> = (0..size.saturating_sub(4 + 14))
+            .map(|_| *letters.choose(rng).unwrap())
+            .collect();
+        let message_at = rng.gen_range(0..=stream.len());
+        let message: Vec<u8> = LETTERS.choose_multiple(rng, 14).copied().collect();
+        stream.splice(message_at..message_at, message);
+        let packet_at = rng.gen_range(0..=message_at);
+        let packet: Vec<u8> = LETTERS.choose_multiple(rng, 4).copied().collect();
+        stream.splice(packet_at..packet_at, packet);
+        String::from_utf8(stream).unwrap() + "\n"
+    }
+}
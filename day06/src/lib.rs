@@ -1,25 +1,243 @@
-use std::collections::HashSet;
+mod generate;
 
-pub fn solution_a(input: &[u8], window: usize) -> usize {
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    ops::ControlFlow,
+};
+
+pub use solver::ReadError;
+use solver::{ParseError, Solver};
+
+pub struct Day06;
+
+pub struct Params {
+    pub packet_window: usize,
+    pub message_window: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            packet_window: 4,
+            message_window: 14,
+        }
+    }
+}
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a [u8];
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        solution_a(input, params.packet_window)
+    }
+
+    fn part_b(input: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        solution_a(input, params.message_window)
+    }
+}
+
+fn parse(input: &str) -> Result<&[u8], ParseError> {
+    let datastream = input.trim_end();
+    match datastream.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(bad) => Err(ParseError::new(
+            input,
+            &datastream[bad..],
+            "expected a lowercase letter",
+        )),
+        None => Ok(datastream.as_bytes()),
+    }
+}
+
+/// How many letters it takes to the end of the first `window` letters in a row that are all
+/// different, `None` when there are none.
+pub fn solution_a(input: &[u8], window: usize) -> Option<usize> {
+    let mut markers = Markers::new();
     input
-        .windows(window)
-        .position(|window| HashSet::<_>::from_iter(window.iter()).len() == window.len())
-        .unwrap()
-        + window
+        .iter()
+        .position(|letter| markers.push(*letter) >= window)
+        .map(|position| position + 1)
+}
+
+/// Follows a datastream a letter at a time, keeping how many letters in a row up to the last one
+/// are all different, so a marker of any length ends wherever that's at least its length. Any
+/// byte counts as a letter.
+#[derive(Debug, Clone)]
+pub struct Markers {
+    /// Letters taken so far.
+    position: usize,
+    /// By letter, the position after it was last taken, 0 before it is.
+    after: [usize; 256],
+    /// Where the letters that are all different up to the last one start.
+    start: usize,
+}
+
+impl Default for Markers {
+    fn default() -> Self {
+        Self {
+            position: 0,
+            after: [0; 256],
+            start: 0,
+        }
+    }
+}
+
+impl Markers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Letters taken so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Takes the next letter, returning how many letters in a row up to it are all different.
+    pub fn push(&mut self, letter: u8) -> usize {
+        let after = &mut self.after[letter as usize];
+        self.start = self.start.max(*after);
+        self.position += 1;
+        *after = self.position;
+        self.position - self.start
+    }
+}
+
+/// The end of the first marker of each length in `windows`, in the same order, reading `reader`
+/// once and only as far as the last of them.
+pub fn first_markers(
+    reader: impl Read,
+    windows: &[usize],
+) -> Result<Vec<Option<usize>>, ReadError> {
+    let mut firsts = vec![None; windows.len()];
+    let mut left = windows.len();
+    scan(reader, |markers, run| {
+        for (first, window) in firsts.iter_mut().zip(windows) {
+            if first.is_none() && run >= *window {
+                *first = Some(markers.position());
+                left -= 1;
+            }
+        }
+        match left {
+            0 => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    })?;
+    Ok(firsts)
+}
+
+/// The end of every marker of length `window`, overlapping ones included, in order.
+pub fn all_markers(reader: impl Read, window: usize) -> Result<Vec<usize>, ReadError> {
+    let mut ends = vec![];
+    scan(reader, |markers, run| {
+        if run >= window {
+            ends.push(markers.position());
+        }
+        ControlFlow::Continue(())
+    })?;
+    Ok(ends)
+}
+
+/// Feeds the letters of `reader` to `visit` with how many in a row up to each are all different,
+/// a chunk at a time. Whitespace may only end the datastream, like [`Day06`] trims it.
+fn scan(
+    mut reader: impl Read,
+    mut visit: impl FnMut(&Markers, usize) -> ControlFlow<()>,
+) -> Result<(), ReadError> {
+    let mut markers = Markers::new();
+    let mut chunk = [0; 8192];
+    // the first whitespace, which is only fine if nothing else comes after it
+    let mut space: Option<u8> = None;
+    loop {
+        let len = match reader.read(&mut chunk) {
+            Ok(0) => return Ok(()),
+            Ok(len) => len,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+        for byte in &chunk[..len] {
+            if byte.is_ascii_whitespace() {
+                space.get_or_insert(*byte);
+                continue;
+            }
+            if let Some(bad) = space.or((!byte.is_ascii_lowercase()).then_some(*byte)) {
+                // everything before it is a letter, so the position is its column
+                return Err(ReadError::Parse(ParseError {
+                    line: 1,
+                    column: markers.position() + 1,
+                    text: match bad {
+                        b'\n' => String::new(),
+                        _ => (bad as char).to_string(),
+                    },
+                    message: "expected a lowercase letter".to_string(),
+                }));
+            }
+            let run = markers.push(*byte);
+            if visit(&markers, run).is_break() {
+                return Ok(());
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &[u8] = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes();
-    const INPUT: &[u8] = include_bytes!("input.txt");
+    const INPUT: &str = include_str!("input.txt");
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT, 4), 7);
-        println!("{}", solution_a(INPUT, 4));
-        assert_eq!(solution_a(TEST_INPUT, 14), 19);
-        println!("{}", solution_a(INPUT, 14));
+        let input = input::or_embedded(Day06::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT, 4), Some(7));
+        println!("{}", solution_a(input.as_bytes(), 4).unwrap());
+        assert_eq!(solution_a(TEST_INPUT, 14), Some(19));
+        println!("{}", solution_a(input.as_bytes(), 14).unwrap());
+        assert_eq!(solution_a(b"abc", 4), None);
+        assert_eq!(solution_a(b"AAbC\x00\xff", 4), Some(5));
+
+        let firsts = first_markers(input.as_bytes(), &[4, 14]).unwrap();
+        assert_eq!(
+            firsts,
+            vec![
+                solution_a(input.as_bytes(), 4),
+                solution_a(input.as_bytes(), 14)
+            ]
+        );
+        // a byte per read, so every letter is at the end of a chunk
+        struct Trickle<'a>(&'a [u8]);
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let len = self.0.len().min(buf.len()).min(1);
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                Ok(len)
+            }
+        }
+        assert_eq!(
+            first_markers(Trickle(TEST_INPUT), &[14, 4, 27]).unwrap(),
+            vec![Some(19), Some(7), None]
+        );
+        assert_eq!(
+            all_markers(Trickle(b"abcabcd\n"), 3).unwrap(),
+            vec![3, 4, 5, 6, 7]
+        );
+        assert_eq!(all_markers(&b"aabbaab"[..], 2).unwrap(), vec![3, 5, 7]);
+        // reading stops at the last marker, so a bad letter after it goes unread
+        assert!(first_markers(&b"abcd1"[..], &[4]).is_ok());
+        // the same errors as parsing the whole datastream
+        for stream in ["abc1", "ab\ncd", "ab \nc"] {
+            match all_markers(stream.as_bytes(), 4) {
+                Err(ReadError::Parse(err)) => assert_eq!(Err(err), parse(stream)),
+                result => panic!("expected a parse error, got {result:?}"),
+            }
+        }
     }
 }
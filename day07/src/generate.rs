@@ -0,0 +1,94 @@
+//! A terminal session exploring a filesystem, `size` counts the files.
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::Day07;
+
+#[derive(Default)]
+struct Dir {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(u32, String)>,
+}
+
+impl Generate for Day07 {
+    const SIZE: usize = 271;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut dirs = vec![Dir {
+            name: "/".to_string(),
+            ..Dir::default()
+        }];
+        for i in 1..=size * 2 / 3 {
+            let parent = rng.gen_range(0..i);
+            let name = loop {
+                let name = word(rng);
+                if dirs[parent].dirs.iter().all(|dir| dirs[*dir].name != name) {
+                    break name;
+                }
+            };
+            dirs.push(Dir {
+                name,
+                ..Dir::default()
+            });
+            dirs[parent].dirs.push(i);
+        }
+
+        // the disk has to be too full for the update but not overflowing, so the sizes are
+        // scaled to a total between 40000000 and 70000000
+        let weights: Vec<f64> = (0..size.max(1))
+            .map(|_| 10f64.powf(rng.gen_range(0.0..5.0)))
+            .collect();
+        let total = rng.gen_range(41_000_000.0..69_000_000.0) / weights.iter().sum::<f64>();
+        for weight in weights {
+            let dir = rng.gen_range(0..dirs.len());
+            let name = format!(
+                "{}.{}",
+                word(rng),
+                ["txt", "dat", "log", "bin"].choose(rng).unwrap()
+            );
+            dirs[dir]
+                .files
+                .push(((weight * total).max(1.0) as u32, name));
+        }
+
+        let mut session = "$ cd /\n".to_string();
+        list(&dirs, 0, &mut session, rng);
+        session
+    }
+}
+
+fn word(rng: &mut impl Rng) -> String {
+    (0..rng.gen_range(1..=8))
+        .map(|_| rng.gen_range('a'..='z'))
+        .collect()
+}
+
+/// Lists `dir` and then changes into each of its directories in turn to list them too.
+fn list(dirs: &[Dir], dir: usize, session: &mut String, rng: &mut impl Rng) {
+    let dir = &dirs[dir];
+    let mut entries: Vec<String> = dir
+        .dirs
+        .iter()
+        .map(|child| format!("dir {}", dirs[*child].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(size, name)| format!("{size} {name}")),
+        )
+        .collect();
+    entries.shuffle(rng);
+    session.push_str("$ ls\n");
+    for entry in entries {
+        session.push_str(&entry);
+        session.push('\n');
+    }
+    for child in &dir.dirs {
+        session.push_str(&format!("$ cd {}\n", dirs[*child].name));
+        list(dirs, *child, session, rng);
+        session.push_str("$ cd ..\n");
+    }
+}
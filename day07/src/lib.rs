@@ -1,4 +1,9 @@
-use std::collections::HashMap;
+mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
 
 use nom::{
     branch::alt,
@@ -6,67 +11,355 @@ use nom::{
     character::complete::{self, newline, not_line_ending},
     combinator::map,
     multi::separated_list1,
-    sequence::pair,
+    sequence::{pair, separated_pair},
     IResult,
 };
+use solver::{parse, ParseError, Solver};
 
-#[derive(Debug)]
-enum Entry {
+/// A line of the transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
     Cd(String),
     Ls,
     Dir(String),
-    File(u32),
+    File { size: u32, name: String },
 }
 
-pub fn solution_a(input: &str) -> u32 {
-    let (_, entries) = parse(input).unwrap();
-    let sizes = calculate_dir_sizes(entries);
-    sizes.values().filter(|size| **size <= 100000).sum()
+/// A directory or a file, by its place in the [`Tree`].
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    /// `None` for `/`.
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
 }
 
-pub fn solution_b(input: &str) -> u32 {
-    let (_, entries) = parse(input).unwrap();
-    let sizes = calculate_dir_sizes(entries);
-    let unused = 70000000 - sizes["/"];
-    let size_to_delete = 30000000 - unused;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    /// The children in the order they're first listed or visited.
+    Dir {
+        children: Vec<NodeId>,
+    },
+    File {
+        size: u32,
+    },
+}
 
-    *sizes
-        .values()
-        .filter(|size| **size >= size_to_delete)
-        .min()
-        .unwrap()
+/// How files listed again in the same directory count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Listings {
+    /// Every listing adds its files to the sizes, like the puzzle expects.
+    #[default]
+    Sum,
+    /// A file listed again is the same file, at the size it's last listed with.
+    Merge,
 }
 
-fn calculate_dir_sizes(entries: Vec<Entry>) -> HashMap<String, u32> {
-    let mut current_dir = vec![];
-    let mut sizes = HashMap::<String, u32>::new();
-    for e in entries {
-        match e {
-            Entry::Cd(dir) => match dir.as_str() {
-                "/" => {
-                    current_dir.clear();
-                    current_dir.push(dir);
-                }
-                ".." => {
-                    current_dir.pop();
+/// Something in a transcript that doesn't add up, by the 1-based line it's on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anomaly {
+    /// `ls` of the directory at `path`, which was listed on line `first` already.
+    Relisted {
+        line: usize,
+        path: String,
+        first: usize,
+    },
+    /// `cd` into the directory at `path`, which no `ls` has shown.
+    Unlisted { line: usize, path: String },
+    /// `cd ..` in `/`.
+    AboveRoot { line: usize },
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::Relisted { line, path, first } => {
+                write!(f, "line {line}: {path} was listed on line {first} already")
+            }
+            Anomaly::Unlisted { line, path } => {
+                write!(f, "line {line}: {path} wasn't listed before cd into it")
+            }
+            Anomaly::AboveRoot { line } => write!(f, "line {line}: cd .. in /"),
+        }
+    }
+}
+
+/// Everything in the transcript that doesn't add up, in order.
+pub fn validate(entries: &[Entry]) -> Vec<Anomaly> {
+    Tree::build(entries, Listings::Sum).1
+}
+
+/// The filesystem a transcript explores, as far as it shows it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree {
+    /// `/` first and every node after its parent.
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    pub const ROOT: NodeId = 0;
+
+    /// Directories listed or visited twice are the same directory and like a shell `cd ..` in `/`
+    /// stays in `/`, [`validate`] reports these.
+    pub fn new(entries: &[Entry], listings: Listings) -> Self {
+        Self::build(entries, listings).0
+    }
+
+    fn build(entries: &[Entry], listings: Listings) -> (Self, Vec<Anomaly>) {
+        let mut tree = Self {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                kind: Kind::Dir { children: vec![] },
+            }],
+        };
+        let mut anomalies = vec![];
+        // the line of the first `ls` in each directory, and the directories an `ls` has shown
+        let mut listed = HashMap::new();
+        let mut shown = HashSet::from([Self::ROOT]);
+        let mut cwd = Self::ROOT;
+        for (line, entry) in entries.iter().enumerate().map(|(i, entry)| (i + 1, entry)) {
+            match entry {
+                Entry::Cd(dir) => {
+                    cwd = match dir.as_str() {
+                        "/" => Self::ROOT,
+                        ".." => tree.nodes[cwd].parent.unwrap_or_else(|| {
+                            anomalies.push(Anomaly::AboveRoot { line });
+                            Self::ROOT
+                        }),
+                        _ => {
+                            let dir = tree.dir(cwd, dir);
+                            if !shown.contains(&dir) {
+                                let path = tree.path(dir);
+                                anomalies.push(Anomaly::Unlisted { line, path });
+                            }
+                            dir
+                        }
+                    }
                 }
-                _ => {
-                    current_dir.push(dir);
+                Entry::Ls => match listed.get(&cwd) {
+                    Some(&first) => {
+                        let path = tree.path(cwd);
+                        anomalies.push(Anomaly::Relisted { line, path, first });
+                    }
+                    None => {
+                        listed.insert(cwd, line);
+                    }
+                },
+                Entry::Dir(dir) => {
+                    shown.insert(tree.dir(cwd, dir));
                 }
-            },
-            Entry::Ls => {}
-            Entry::Dir(_) => {}
-            Entry::File(size) => {
-                for i in 0..current_dir.len() {
-                    sizes
-                        .entry(current_dir[..=i].join(","))
-                        .and_modify(|s| *s += size)
-                        .or_insert(size);
+                Entry::File { size, name } => {
+                    let listed = tree.children(cwd).iter().copied().find(|child| {
+                        let node = &tree.nodes[*child];
+                        node.name == *name && matches!(node.kind, Kind::File { .. })
+                    });
+                    match (listings, listed) {
+                        (Listings::Merge, Some(file)) => {
+                            tree.nodes[file].kind = Kind::File { size: *size };
+                        }
+                        _ => {
+                            tree.add(cwd, name, Kind::File { size: *size });
+                        }
+                    }
                 }
             }
-        };
+        }
+        (tree, anomalies)
+    }
+
+    /// The directory `name` in `parent`, added if there isn't one.
+    fn dir(&mut self, parent: NodeId, name: &str) -> NodeId {
+        let existing = self.children(parent).iter().copied().find(|child| {
+            let node = &self.nodes[*child];
+            node.name == name && matches!(node.kind, Kind::Dir { .. })
+        });
+        existing.unwrap_or_else(|| self.add(parent, name, Kind::Dir { children: vec![] }))
+    }
+
+    fn add(&mut self, parent: NodeId, name: &str, kind: Kind) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(parent),
+            kind,
+        });
+        if let Kind::Dir { children } = &mut self.nodes[parent].kind {
+            children.push(id);
+        }
+        id
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    /// Empty for a file.
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        match &self.nodes[id].kind {
+            Kind::Dir { children } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    /// Every directory, `/` first and each before what's in it.
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|id| matches!(self.nodes[*id].kind, Kind::Dir { .. }))
+    }
+
+    /// The node at an absolute path like `/a/e`, `None` if the transcript never shows it.
+    pub fn get(&self, path: &str) -> Option<NodeId> {
+        let path = path.strip_prefix('/')?;
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::ROOT, |dir, name| {
+                self.children(dir)
+                    .iter()
+                    .copied()
+                    .find(|child| self.nodes[*child].name == name)
+            })
+    }
+
+    /// The absolute path of a node, e.g. `/a/e`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut node = id;
+        while let Some(parent) = self.nodes[node].parent {
+            names.push(self.nodes[node].name.as_str());
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The size of a file, or of everything in a directory.
+    pub fn size(&self, id: NodeId) -> u32 {
+        match &self.nodes[id].kind {
+            Kind::Dir { children } => children.iter().map(|child| self.size(*child)).sum(),
+            Kind::File { size } => *size,
+        }
+    }
+
+    /// The size of every node, by id.
+    pub fn sizes(&self) -> Vec<u32> {
+        let mut sizes: Vec<u32> = self
+            .nodes
+            .iter()
+            .map(|node| match node.kind {
+                Kind::Dir { .. } => 0,
+                Kind::File { size } => size,
+            })
+            .collect();
+        // children come after their parent, so each node is done before it adds to its parent
+        for (id, node) in self.nodes.iter().enumerate().rev() {
+            if let Some(parent) = node.parent {
+                sizes[parent] += sizes[id];
+            }
+        }
+        sizes
+    }
+
+    /// The path and size of every directory like `du` lists them, each after what's in it.
+    pub fn du(&self) -> Vec<(String, u32)> {
+        let sizes = self.sizes();
+        let mut dirs = vec![];
+        self.du_from(Self::ROOT, &sizes, &mut dirs);
+        dirs
+    }
+
+    fn du_from(&self, dir: NodeId, sizes: &[u32], dirs: &mut Vec<(String, u32)>) {
+        for child in self.children(dir) {
+            if matches!(self.nodes[*child].kind, Kind::Dir { .. }) {
+                self.du_from(*child, sizes, dirs);
+            }
+        }
+        dirs.push((self.path(dir), sizes[dir]));
+    }
+
+    /// The total size of the directories of at most `limit`, counting nested ones again.
+    pub fn sum_of_dirs_up_to(&self, limit: u32) -> u32 {
+        let sizes = self.sizes();
+        self.dirs()
+            .map(|dir| sizes[dir])
+            .filter(|size| *size <= limit)
+            .sum()
+    }
+
+    /// The size of the smallest directory to delete to have `needed` free on a disk of `disk`,
+    /// `None` if even deleting everything isn't enough.
+    pub fn smallest_dir_freeing(&self, disk: u32, needed: u32) -> Option<u32> {
+        let sizes = self.sizes();
+        let unused = disk.saturating_sub(sizes[Self::ROOT]);
+        let to_delete = needed.saturating_sub(unused);
+        self.dirs()
+            .map(|dir| sizes[dir])
+            .filter(|size| *size >= to_delete)
+            .min()
+    }
+}
+
+/// Like the puzzle draws it, e.g. `  - b.txt (file, size=14848514)` under `- / (dir)`.
+impl Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack = vec![(Self::ROOT, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let node = &self.nodes[id];
+            let indent = "  ".repeat(depth);
+            match node.kind {
+                Kind::Dir { .. } => writeln!(f, "{indent}- {} (dir)", node.name)?,
+                Kind::File { size } => writeln!(f, "{indent}- {} (file, size={size})", node.name)?,
+            }
+            stack.extend(
+                self.children(id)
+                    .iter()
+                    .rev()
+                    .map(|child| (*child, depth + 1)),
+            );
+        }
+        Ok(())
+    }
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    type Parsed<'a> = Vec<Entry>;
+    type Params = Listings;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_entries(input)
+    }
+
+    fn part_a(entries: &Self::Parsed<'_>, listings: &Listings) -> Option<impl Display> {
+        Some(Tree::new(entries, *listings).sum_of_dirs_up_to(100000))
+    }
+
+    fn part_b(entries: &Self::Parsed<'_>, listings: &Listings) -> Option<impl Display> {
+        Tree::new(entries, *listings).smallest_dir_freeing(70000000, 30000000)
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<u32, ParseError> {
+    Ok(Tree::new(&parse_entries(input)?, Listings::Sum).sum_of_dirs_up_to(100000))
+}
+
+pub fn solution_b(input: &str) -> Result<Option<u32>, ParseError> {
+    Ok(Tree::new(&parse_entries(input)?, Listings::Sum).smallest_dir_freeing(70000000, 30000000))
+}
+
+fn parse_entries(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let entries = parse::finish(input, parse(input))?;
+    match entries.first() {
+        Some(Entry::Cd(dir)) if dir == "/" => Ok(entries),
+        _ => Err(ParseError::new(
+            input,
+            input.lines().next().unwrap_or_default(),
+            "expected the transcript to start with $ cd /",
+        )),
     }
-    sizes
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<Entry>> {
@@ -84,8 +377,11 @@ fn parse_line(line: &str) -> IResult<&str, Entry> {
             Entry::Dir(dir.to_string())
         }),
         map(
-            pair(complete::u32, not_line_ending::<&str, _>),
-            |(size, _)| Entry::File(size),
+            separated_pair(complete::u32, tag(" "), not_line_ending::<&str, _>),
+            |(size, name)| Entry::File {
+                size,
+                name: name.to_string(),
+            },
         ),
     ))(line)
 }
@@ -93,6 +389,7 @@ fn parse_line(line: &str) -> IResult<&str, Entry> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "$ cd /
 $ ls
@@ -121,9 +418,90 @@ $ ls
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 95437);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 24933642);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day07::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(95437));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(Some(24933642)));
+        println!("{}", solution_b(&input).unwrap().unwrap());
+
+        let err = solution_a("$ ls\n100 a").unwrap_err();
+        assert_eq!(err.message, "expected the transcript to start with $ cd /");
+        assert_eq!(solution_b("$ cd /\n$ cd ..\n$ ls\n100 a"), Ok(Some(100)));
+
+        let tree = Tree::new(&parse_entries(TEST_INPUT).unwrap(), Listings::Sum);
+        assert_eq!(
+            tree.to_string(),
+            "- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - j (file, size=4060174)
+    - d.log (file, size=8033020)
+    - d.ext (file, size=5626152)
+    - k (file, size=7214296)
+"
+        );
+        let e = tree.get("/a/e").unwrap();
+        assert_eq!((tree.path(e), tree.size(e)), ("/a/e".to_string(), 584));
+        assert_eq!(tree.get("/"), Some(Tree::ROOT));
+        assert_eq!(tree.get("/d/j").map(|j| tree.size(j)), Some(4060174));
+        assert_eq!(tree.get("/a/x"), None);
+        assert_eq!(
+            tree.du(),
+            vec![
+                ("/a/e".to_string(), 584),
+                ("/a".to_string(), 94853),
+                ("/d".to_string(), 24933642),
+                ("/".to_string(), 48381165)
+            ]
+        );
+        assert_eq!(tree.smallest_dir_freeing(48381165, 48381166), None);
+
+        assert_eq!(validate(&parse_entries(TEST_INPUT).unwrap()), vec![]);
+        assert!(validate(&parse_entries(&input).unwrap()).is_empty());
+        let entries = parse_entries(
+            "$ cd /
+$ ls
+dir a
+100 b
+$ cd ..
+$ cd c
+$ cd /
+$ ls
+dir a
+100 b
+200 d",
+        )
+        .unwrap();
+        let anomalies = validate(&entries);
+        assert_eq!(
+            anomalies,
+            vec![
+                Anomaly::AboveRoot { line: 5 },
+                Anomaly::Unlisted {
+                    line: 6,
+                    path: "/c".to_string()
+                },
+                Anomaly::Relisted {
+                    line: 8,
+                    path: "/".to_string(),
+                    first: 2
+                },
+            ]
+        );
+        assert_eq!(
+            anomalies[2].to_string(),
+            "line 8: / was listed on line 2 already"
+        );
+        let sum = Tree::new(&entries, Listings::Sum);
+        let merged = Tree::new(&entries, Listings::Merge);
+        assert_eq!((sum.size(Tree::ROOT), merged.size(Tree::ROOT)), (400, 300));
+        assert_eq!(merged.get("/b").map(|b| merged.size(b)), Some(100));
     }
 }
@@ -0,0 +1,23 @@
+//! A square patch of trees, `size` is its side.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day08;
+
+impl Generate for Day08 {
+    const SIZE: usize = 99;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        // the scenic scores are of the trees inside the edge, so there has to be one
+        let size = size.max(3);
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
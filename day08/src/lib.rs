@@ -1,20 +1,47 @@
+mod generate;
+
+use std::fmt::Display;
+
+use grid::Grid;
 use itertools::Itertools;
+use solver::{ParseError, Solver};
 
-fn parse_grid(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|char| char.to_digit(10).unwrap())
-                .collect()
-        })
-        .collect()
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    type Parsed<'a> = Grid<u32>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part_a(grid: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(grid))
+    }
+
+    fn part_b(grid: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(grid))
+    }
 }
 
-fn get_up_down_left_right(grid: &[Vec<u32>], x: usize, y: usize) -> Vec<Vec<u32>> {
-    let column = grid.iter().map(|row| row[x]).collect::<Vec<u32>>();
+fn parse_grid(input: &str) -> Result<Grid<u32>, ParseError> {
+    let grid = Grid::parse(input, |char| char.to_digit(10))?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::new(
+            input,
+            input,
+            "expected rows of tree heights",
+        ));
+    }
+    Ok(grid)
+}
+
+fn get_up_down_left_right(grid: &Grid<u32>, x: usize, y: usize) -> Vec<Vec<u32>> {
+    let column = grid.column(x).copied().collect::<Vec<u32>>();
     let (up, down) = column.split_at(y);
-    let (left, right) = grid[y].split_at(x);
+    let (left, right) = grid.row(y).split_at(x);
     vec![
         up.iter().rev().cloned().collect(),
         down[1..].to_vec(),
@@ -23,32 +50,37 @@ fn get_up_down_left_right(grid: &[Vec<u32>], x: usize, y: usize) -> Vec<Vec<u32>
     ]
 }
 
-pub fn solution_a(input: &str) -> usize {
-    let grid = parse_grid(input);
-    let len = grid.len();
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(&parse_grid(input)?))
+}
 
-    (1..len - 1)
-        .cartesian_product(1..len - 1)
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(&parse_grid(input)?))
+}
+
+fn part_a(grid: &Grid<u32>) -> usize {
+    let (width, height) = (grid.width(), grid.height());
+
+    (1..height - 1)
+        .cartesian_product(1..width - 1)
         .map(|(y, x)| {
-            let height = grid[y][x];
-            get_up_down_left_right(&grid, x, y)
+            let height = grid[(x, y)];
+            get_up_down_left_right(grid, x, y)
                 .iter()
-                .map(|direction| direction.iter().all(|h| *h < height))
-                .any(|direction_visible| direction_visible)
+                .any(|direction| direction.iter().all(|h| *h < height))
         })
         .filter(|direction_visible| *direction_visible)
         .count()
-        + (len - 1) * 4
+        + width * height
+        - width.saturating_sub(2) * height.saturating_sub(2)
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let grid = parse_grid(input);
-    let len = grid.len();
-    (1..len - 1)
-        .cartesian_product(1..len - 1)
+fn part_b(grid: &Grid<u32>) -> usize {
+    (1..grid.height() - 1)
+        .cartesian_product(1..grid.width() - 1)
         .map(|(y, x)| {
-            let height = grid[y][x];
-            get_up_down_left_right(&grid, x, y)
+            let height = grid[(x, y)];
+            get_up_down_left_right(grid, x, y)
                 .iter()
                 .map(|direction| {
                     direction
@@ -60,12 +92,13 @@ pub fn solution_b(input: &str) -> usize {
                 .product()
         })
         .max()
-        .unwrap()
+        .unwrap_or(0)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "30373
 25512
@@ -77,9 +110,14 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 21);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 8);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day08::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(21));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(8));
+        println!("{}", solution_b(&input).unwrap());
+
+        assert!(solution_a("\n").is_err());
+        assert_eq!(solution_a("123"), Ok(3));
+        assert_eq!(solution_b("123"), Ok(0));
     }
 }
@@ -0,0 +1,18 @@
+//! Motions of the head of the rope, `size` counts them.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day09;
+
+impl Generate for Day09 {
+    const SIZE: usize = 2000;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let direction = ["U", "D", "L", "R"][rng.gen_range(0..4)];
+                format!("{direction} {}\n", rng.gen_range(1..=19))
+            })
+            .collect()
+    }
+}
@@ -1,72 +1,163 @@
-use std::collections::HashSet;
+mod generate;
 
-struct Cmd {
-    direction: Direction,
-    amount: i32,
+use std::{collections::HashSet, fmt::Display};
+
+use geometry::{Dir4, Point};
+use render::{Frame, Recorder, Visualise};
+use solver::{parse, ParseError, Solver};
+
+pub struct Day09;
+
+pub struct Params {
+    pub knots_a: usize,
+    pub knots_b: usize,
 }
 
-#[derive(Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            knots_a: 2,
+            knots_b: 10,
+        }
+    }
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-struct Coord {
-    x: i32,
-    y: i32,
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    type Parsed<'a> = Vec<Cmd>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(cmds: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(simulate(cmds, params.knots_a, None))
+    }
+
+    fn part_b(cmds: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(simulate(cmds, params.knots_b, None))
+    }
 }
 
-pub fn solution_a(input: &str, n_knots: usize) -> usize {
-    let cmds = parse(input);
-    let mut p = vec![Coord { x: 0, y: 0 }; n_knots];
-    let mut visited = HashSet::<Coord>::new();
-    visited.insert(Coord { x: 0, y: 0 });
+/// A frame each time the head moves one step.
+impl Visualise for Day09 {
+    fn visualise_a(
+        cmds: &Self::Parsed<'_>,
+        params: &Params,
+        recorder: &mut Recorder,
+    ) -> impl Display {
+        simulate(cmds, params.knots_a, Some(recorder))
+    }
+
+    fn visualise_b(
+        cmds: &Self::Parsed<'_>,
+        params: &Params,
+        recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        Some(simulate(cmds, params.knots_b, Some(recorder)))
+    }
+}
+
+pub struct Cmd {
+    direction: Dir4,
+    amount: i32,
+}
+
+pub fn solution_a(input: &str, n_knots: usize) -> Result<usize, ParseError> {
+    Ok(simulate(&parse(input)?, n_knots, None))
+}
+
+fn simulate(cmds: &[Cmd], n_knots: usize, mut recorder: Option<&mut Recorder>) -> usize {
+    let bounds = recorder.as_ref().map(|_| bounds(cmds));
+    let mut p = vec![Point::new(0, 0); n_knots];
+    let mut visited = HashSet::<Point<i32>>::new();
+    visited.insert(Point::new(0, 0));
 
     for cmd in cmds {
         for _ in 0..cmd.amount {
-            match cmd.direction {
-                Direction::Up => p[0].y += 1,
-                Direction::Down => p[0].y -= 1,
-                Direction::Left => p[0].x -= 1,
-                Direction::Right => p[0].x += 1,
-            }
+            p[0] = p[0].step(cmd.direction);
 
             for i in 0..p.len() - 1 {
-                let dx = p[i].x - p[i + 1].x;
-                let dy = p[i].y - p[i + 1].y;
-
-                let not_touching = dx.abs() > 1 || dy.abs() > 1;
+                let not_touching = p[i].chebyshev(p[i + 1]) > 1;
                 if not_touching {
-                    p[i + 1].x += dx.signum();
-                    p[i + 1].y += dy.signum();
+                    let towards = (p[i] - p[i + 1]).signum();
+                    p[i + 1] += towards;
                 }
             }
 
             visited.insert(p[p.len() - 1]);
+            if let (Some(recorder), Some(bounds)) = (recorder.as_deref_mut(), bounds) {
+                recorder.frame(|| draw(&p, &visited, bounds));
+            }
         }
     }
     visited.len()
 }
 
-fn parse(input: &str) -> Vec<Cmd> {
+/// Corners of the area the head covers, the knots following it can't leave it.
+fn bounds(cmds: &[Cmd]) -> (Point<i32>, Point<i32>) {
+    let mut head = Point::new(0, 0);
+    let (mut min, mut max) = (head, head);
+    for cmd in cmds {
+        for _ in 0..cmd.amount {
+            head = head.step(cmd.direction);
+            min = Point::new(min.x.min(head.x), min.y.min(head.y));
+            max = Point::new(max.x.max(head.x), max.y.max(head.y));
+        }
+    }
+    (min, max)
+}
+
+/// The rope like the puzzle draws it, with `#` where the tail has been.
+fn draw(
+    knots: &[Point<i32>],
+    visited: &HashSet<Point<i32>>,
+    (min, max): (Point<i32>, Point<i32>),
+) -> Frame {
+    let mut frame = Frame::new(
+        (max.x - min.x + 1) as usize,
+        (max.y - min.y + 1) as usize,
+        '.',
+    );
+    let cell = |knot: Point<i32>| ((knot.x - min.x) as usize, (knot.y - min.y) as usize);
+    for position in visited {
+        frame[cell(*position)] = '#';
+    }
+    frame[cell(Point::new(0, 0))] = 's';
+    // knots further up the rope are drawn over the ones behind them
+    for (i, knot) in knots.iter().enumerate().rev() {
+        frame[cell(*knot)] = match i {
+            0 => 'H',
+            _ if knots.len() == 2 => 'T',
+            _ => char::from_digit(i as u32, 36).unwrap_or('T'),
+        };
+    }
+    frame
+}
+
+fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
     input
         .lines()
-        .map(|line| line.split_once(' ').unwrap())
-        .map(|(dir, amt)| {
+        .map(|line| {
+            let Some((dir, amt)) = line.split_once(' ') else {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "expected a direction and an amount",
+                ));
+            };
             let dir = match dir {
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                "L" => Direction::Left,
-                "R" => Direction::Right,
-                _ => unreachable!(),
+                "U" => Dir4::Up,
+                "D" => Dir4::Down,
+                "L" => Dir4::Left,
+                "R" => Dir4::Right,
+                _ => return Err(ParseError::new(input, dir, "expected U, D, L or R")),
             };
-            Cmd {
+            Ok(Cmd {
                 direction: dir,
-                amount: amt.parse().unwrap(),
-            }
+                amount: parse::number(input, amt)?,
+            })
         })
         .collect()
 }
@@ -74,6 +165,7 @@ fn parse(input: &str) -> Vec<Cmd> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT1: &str = "R 4
 U 4
@@ -97,9 +189,10 @@ U 20";
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT1, 2), 13);
-        println!("{}", solution_a(INPUT, 2));
-        assert_eq!(solution_a(TEST_INPUT2, 10), 36);
-        println!("{}", solution_a(INPUT, 10));
+        let input = input::or_embedded(Day09::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT1, 2), Ok(13));
+        println!("{}", solution_a(&input, 2).unwrap());
+        assert_eq!(solution_a(TEST_INPUT2, 10), Ok(36));
+        println!("{}", solution_a(&input, 10).unwrap());
     }
 }
@@ -0,0 +1,31 @@
+//! A CRT program, `size` counts the cycles it runs for. The screen is only 240 pixels, so that's
+//! the most it can run for.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day10;
+
+const CYCLES: usize = 240;
+
+impl Generate for Day10 {
+    const SIZE: usize = CYCLES;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut program = String::new();
+        let mut cycles = size.clamp(1, CYCLES);
+        // keep the sprite around the screen like the real programs do
+        let mut x: i32 = 1;
+        while cycles > 0 {
+            if cycles == 1 || rng.gen_bool(0.3) {
+                program.push_str("noop\n");
+                cycles -= 1;
+            } else {
+                let v = rng.gen_range(-(x + 1).min(20)..=(39 - x).min(20));
+                x += v;
+                program.push_str(&format!("addx {v}\n"));
+                cycles -= 2;
+            }
+        }
+        program
+    }
+}
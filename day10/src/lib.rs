@@ -1,3 +1,7 @@
+mod generate;
+
+use std::fmt::Display;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -7,9 +11,11 @@ use nom::{
     sequence::preceded,
     IResult,
 };
+use solver::{parse, ParseError, Solver};
+use tracing::debug;
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Addx(i32),
     Noop,
 }
@@ -24,8 +30,38 @@ fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
     separated_list1(newline, parse_line)(input)
 }
 
-pub fn solution(input: &str) -> i32 {
-    let (_, instructions) = parse(input).unwrap();
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Parsed<'a> = Vec<Instruction>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::finish(input, parse(input))
+    }
+
+    fn part_a(instructions: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        let (signal, _) = run(instructions);
+        Some(signal)
+    }
+
+    fn part_b(instructions: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        let (_, image) = run(instructions);
+        Some(image)
+    }
+}
+
+pub fn solution(input: &str) -> Result<i32, ParseError> {
+    let instructions = parse::finish(input, parse(input))?;
+    let (signal, image) = run(&instructions);
+    debug!("image\n{image}");
+
+    Ok(signal)
+}
+
+/// Returns the signal strength and the image drawn on the CRT.
+fn run(instructions: &[Instruction]) -> (i32, String) {
     let mut x = 1;
     let mut cycle = 0;
     let mut signal = 0;
@@ -37,7 +73,7 @@ pub fn solution(input: &str) -> i32 {
             signal += cycle as i32 * x;
         }
 
-        if let Addx(v) = inst {
+        if let Addx(v) = *inst {
             grid[cycle] = draw_pixel(cycle, x);
             cycle += 1;
             if cycle % 40 == 20 {
@@ -52,9 +88,8 @@ pub fn solution(input: &str) -> i32 {
         .map(|row| String::from_iter(row.iter()))
         .collect::<Vec<String>>()
         .join("\n");
-    println!("{}", image);
 
-    signal
+    (signal, image)
 }
 
 #[inline]
@@ -69,11 +104,13 @@ fn draw_pixel(cycle: usize, x: i32) -> char {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     #[test]
     fn it_works() {
-        assert_eq!(solution(TEST_INPUT), 13140);
-        println!("{}", solution(INPUT));
+        let input = input::or_embedded(Day10::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT), Ok(13140));
+        println!("{}", solution(&input).unwrap());
     }
 
     const INPUT: &str = include_str!("input.txt");
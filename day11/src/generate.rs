@@ -0,0 +1,115 @@
+//! Monkeys playing keep away, `size` counts them. Their tests are distinct primes up to 23, which
+//! keeps the worry levels of part B from overflowing, so there are at most 9.
+
+use std::mem;
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::{Day11, Monkey, Operation, Params, Test, Value};
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generate for Day11 {
+    const SIZE: usize = 8;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let size = size.clamp(2, PRIMES.len());
+        // part A doesn't keep the worry levels in check, so try again until they fit in a u64
+        let monkeys = loop {
+            let monkeys = monkeys(size, rng);
+            if fits(&monkeys) {
+                break monkeys;
+            }
+        };
+        let monkeys: Vec<String> = monkeys.iter().enumerate().map(describe).collect();
+        monkeys.join("\n")
+    }
+}
+
+fn monkeys(size: usize, rng: &mut impl Rng) -> Vec<Monkey> {
+    let divisors: Vec<u64> = PRIMES.choose_multiple(rng, size).copied().collect();
+    let squares = rng.gen_range(0..size);
+    (0..size)
+        .map(|i| {
+            let operation = if i == squares {
+                (Operation::Times, Value::Old)
+            } else if rng.gen_bool(0.3) {
+                (Operation::Times, Value::Number(rng.gen_range(2..=19)))
+            } else {
+                (Operation::Add, Value::Number(rng.gen_range(1..=8)))
+            };
+            let mut other = || (i + rng.gen_range(1..size)) % size;
+            let case_true = other();
+            let case_false = loop {
+                let case_false = other();
+                if case_false != case_true || size == 2 {
+                    break case_false;
+                }
+            };
+            Monkey {
+                items: (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(50..=99))
+                    .collect(),
+                operation,
+                test: Test {
+                    divisible: divisors[i],
+                    case_true,
+                    case_false,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Whether the worry levels of part A stay within a u64.
+fn fits(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|monkey| monkey.items.clone()).collect();
+    for _ in 0..Params::default().rounds_a {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for worry in mem::take(&mut items[i]) {
+                let value = match monkey.operation.1 {
+                    Value::Old => worry,
+                    Value::Number(n) => n,
+                };
+                let worry = match monkey.operation.0 {
+                    Operation::Times => worry.checked_mul(value),
+                    Operation::Add => worry.checked_add(value),
+                };
+                let Some(worry) = worry else {
+                    return false;
+                };
+                let worry = worry / 3;
+                items[monkey.test(worry)].push(worry);
+            }
+        }
+    }
+    true
+}
+
+fn describe((i, monkey): (usize, &Monkey)) -> String {
+    let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+    let operation = match monkey.operation.0 {
+        Operation::Times => "*",
+        Operation::Add => "+",
+    };
+    let value = match monkey.operation.1 {
+        Value::Old => "old".to_string(),
+        Value::Number(n) => n.to_string(),
+    };
+    format!(
+        "Monkey {i}:
+  Starting items: {}
+  Operation: new = old {operation} {value}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+        items.join(", "),
+        monkey.test.divisible,
+        monkey.test.case_true,
+        monkey.test.case_false
+    )
+}
@@ -1,3 +1,7 @@
+mod generate;
+
+use std::fmt::Display;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -7,24 +11,29 @@ use nom::{
     sequence::{pair, preceded, tuple},
     IResult,
 };
+use solver::{parse, ParseError, Solver};
 
+#[derive(Clone)]
 enum Operation {
     Times,
     Add,
 }
 
+#[derive(Clone)]
 enum Value {
     Old,
     Number(u64),
 }
 
+#[derive(Clone)]
 struct Test {
     divisible: u64,
     case_true: usize,
     case_false: usize,
 }
 
-struct Monkey {
+#[derive(Clone)]
+pub struct Monkey {
     items: Vec<u64>,
     operation: (Operation, Value),
     test: Test,
@@ -43,7 +52,7 @@ impl Monkey {
     }
 
     fn test(&self, worry: u64) -> usize {
-        if worry % self.test.divisible == 0 {
+        if worry.is_multiple_of(self.test.divisible) {
             self.test.case_true
         } else {
             self.test.case_false
@@ -113,8 +122,73 @@ fn parse(input: &str) -> IResult<&str, Vec<Monkey>> {
     separated_list1(pair(newline, newline), parse_monkey)(input)
 }
 
-pub fn solution(input: &str, rounds: u32, part_a: bool) -> usize {
-    let (_, mut monkeys) = parse(input).unwrap();
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse::finish(input, parse(input))?;
+    let test_lines = input
+        .lines()
+        .filter(|line| line.contains("Test: divisible by"));
+    for (line, monkey) in test_lines.zip(&monkeys) {
+        if monkey.test.divisible == 0 {
+            return Err(ParseError::new(input, line, "cannot divide by zero"));
+        }
+    }
+    let targets = monkeys
+        .iter()
+        .flat_map(|monkey| [monkey.test.case_true, monkey.test.case_false]);
+    let target_lines = input
+        .lines()
+        .filter(|line| line.contains("throw to monkey"));
+    for (line, target) in target_lines.zip(targets) {
+        if target >= monkeys.len() {
+            return Err(ParseError::new(
+                input,
+                line,
+                format!("expected monkeys 0 to {}", monkeys.len() - 1),
+            ));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+pub struct Params {
+    pub rounds_a: u32,
+    pub rounds_b: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            rounds_a: 20,
+            rounds_b: 10000,
+        }
+    }
+}
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Parsed<'a> = Vec<Monkey>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_a(monkeys: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(monkey_business(monkeys.clone(), params.rounds_a, true))
+    }
+
+    fn part_b(monkeys: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(monkey_business(monkeys.clone(), params.rounds_b, false))
+    }
+}
+
+pub fn solution(input: &str, rounds: u32, part_a: bool) -> Result<usize, ParseError> {
+    Ok(monkey_business(parse_monkeys(input)?, rounds, part_a))
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: u32, part_a: bool) -> usize {
     let mut inspections = vec![0; monkeys.len()];
     let ceil: u64 = monkeys.iter().map(|m| m.test.divisible).product();
 
@@ -154,14 +228,23 @@ pub fn solution(input: &str, rounds: u32, part_a: bool) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     #[test]
     fn it_works() {
-        assert_eq!(solution(TEST_INPUT, 20, true), 10605);
-        println!("{}", solution(INPUT, 20, true));
+        let input = input::or_embedded(Day11::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT, 20, true), Ok(10605));
+        println!("{}", solution(&input, 20, true).unwrap());
+
+        assert_eq!(solution(TEST_INPUT, 10000, false), Ok(2713310158));
+        println!("{}", solution(&input, 10000, false).unwrap());
 
-        assert_eq!(solution(TEST_INPUT, 10000, false), 2713310158);
-        println!("{}", solution(INPUT, 10000, false));
+        let input = TEST_INPUT.replace("divisible by 13", "divisible by 0");
+        let err = solution(&input, 20, true).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (18, "cannot divide by zero")
+        );
     }
 
     const INPUT: &str = include_str!("input.txt");
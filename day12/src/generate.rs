@@ -0,0 +1,50 @@
+//! A heightmap, `size` is its width and it's a quarter as high. It's at least 27 wide to climb
+//! from `a` to `z`.
+
+use generator::{
+    rand::{seq::index, Rng},
+    Generate,
+};
+
+use crate::Day12;
+
+impl Generate for Day12 {
+    const SIZE: usize = 162;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let width = size.max(27);
+        let height = (width / 4).max(3);
+        // a ridge climbing one step at a time along a row, from S at the west end to E at the east
+        // end, guarantees a path with the rest of the map rough around it
+        let mut steps = vec![false; width];
+        for x in index::sample(rng, width - 1, 25) {
+            steps[x + 1] = true;
+        }
+        let ridge: Vec<u8> = steps
+            .iter()
+            .scan(0, |elevation, step| {
+                *elevation += *step as u8;
+                Some(*elevation)
+            })
+            .collect();
+        let ridge_row = rng.gen_range(0..height);
+
+        let mut map = String::new();
+        for y in 0..height {
+            for (x, &elevation) in ridge.iter().enumerate() {
+                let square = match (x, y == ridge_row) {
+                    (0, true) => 'S',
+                    (x, true) if x == width - 1 => 'E',
+                    (_, true) => (b'a' + elevation) as char,
+                    _ => {
+                        let rough = elevation as i32 + rng.gen_range(-3..=2);
+                        (b'a' + rough.clamp(0, 25) as u8) as char
+                    }
+                };
+                map.push(square);
+            }
+            map.push('\n');
+        }
+        map
+    }
+}
@@ -1,24 +1,47 @@
-use std::collections::{HashSet, VecDeque};
+mod generate;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Coord {
-    x: usize,
-    y: usize,
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use geometry::Point;
+use grid::Grid;
+use solver::{ParseError, Solver};
+
+pub type Coord = Point<usize>;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Parsed<'a> = (Grid<u8>, Coord, Coord);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a((grid, start, end): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(breadth_first_search(grid, *start, *end))
+    }
+
+    fn part_b((grid, _, end): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(breadth_first_search_from_end(grid, *end))
+    }
 }
 
-pub fn solution_a(input: &str) -> usize {
-    let grid = parse_grid(input);
-    let (start, end) = parse_start_end(input);
-    breadth_first_search(&grid, start, end)
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    let (grid, start, end) = parse(input)?;
+    Ok(breadth_first_search(&grid, start, end))
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let grid = parse_grid(input);
-    let (_, end) = parse_start_end(input);
-    breadth_first_search_from_end(&grid, end)
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    let (grid, _, end) = parse(input)?;
+    Ok(breadth_first_search_from_end(&grid, end))
 }
 
-fn breadth_first_search(grid: &Vec<Vec<u8>>, start: Coord, end: Coord) -> usize {
+fn breadth_first_search(grid: &Grid<u8>, start: Coord, end: Coord) -> usize {
     let mut queue = VecDeque::from([(start, 0)]);
     let mut visited = HashSet::new();
 
@@ -32,7 +55,7 @@ fn breadth_first_search(grid: &Vec<Vec<u8>>, start: Coord, end: Coord) -> usize
         }
         visited.insert(current_node);
         for neighbour in get_neighbours(grid, &current_node) {
-            if grid[neighbour.y][neighbour.x] <= grid[current_node.y][current_node.x] + 1 {
+            if grid[(neighbour.x, neighbour.y)] <= grid[(current_node.x, current_node.y)] + 1 {
                 queue.push_front((neighbour, steps + 1));
             }
         }
@@ -40,13 +63,13 @@ fn breadth_first_search(grid: &Vec<Vec<u8>>, start: Coord, end: Coord) -> usize
     0
 }
 
-fn breadth_first_search_from_end(grid: &Vec<Vec<u8>>, end: Coord) -> usize {
+fn breadth_first_search_from_end(grid: &Grid<u8>, end: Coord) -> usize {
     let mut queue = VecDeque::from([(end, 0)]);
     let mut visited = HashSet::new();
 
     while !queue.is_empty() {
         let (current_node, steps) = queue.pop_back().unwrap();
-        if grid[current_node.y][current_node.x] == b'a' {
+        if grid[(current_node.x, current_node.y)] == b'a' {
             return steps;
         }
         if visited.contains(&current_node) {
@@ -54,7 +77,7 @@ fn breadth_first_search_from_end(grid: &Vec<Vec<u8>>, end: Coord) -> usize {
         }
         visited.insert(current_node);
         for neighbour in get_neighbours(grid, &current_node) {
-            if grid[current_node.y][current_node.x] <= grid[neighbour.y][neighbour.x] + 1 {
+            if grid[(current_node.x, current_node.y)] <= grid[(neighbour.x, neighbour.y)] + 1 {
                 queue.push_front((neighbour, steps + 1));
             }
         }
@@ -62,68 +85,35 @@ fn breadth_first_search_from_end(grid: &Vec<Vec<u8>>, end: Coord) -> usize {
     0
 }
 
-fn get_neighbours(grid: &Vec<Vec<u8>>, coord: &Coord) -> Vec<Coord> {
-    let height = grid.len();
-    let width = grid[0].len();
-
-    let mut neighbours = vec![];
-    if 0 < coord.x {
-        neighbours.push(Coord {
-            x: coord.x - 1,
-            y: coord.y,
-        });
-    }
-    if coord.x < width - 1 {
-        neighbours.push(Coord {
-            x: coord.x + 1,
-            y: coord.y,
-        });
-    }
-    if 0 < coord.y {
-        neighbours.push(Coord {
-            x: coord.x,
-            y: coord.y - 1,
-        });
-    }
-    if coord.y < height - 1 {
-        neighbours.push(Coord {
-            x: coord.x,
-            y: coord.y + 1,
-        });
-    }
-    neighbours
-}
-
-fn parse_grid(input: &str) -> Vec<Vec<u8>> {
-    input
-        .replace('S', "a")
-        .replace('E', "z")
-        .lines()
-        .map(|line| line.bytes().collect::<Vec<u8>>())
-        .collect()
+fn get_neighbours<'a>(grid: &'a Grid<u8>, coord: &Coord) -> impl Iterator<Item = Coord> + 'a {
+    grid.neighbours4((*coord).into()).map(Coord::from)
 }
 
-fn parse_start_end(input: &str) -> (Coord, Coord) {
-    let s = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| (y, line.find('S')))
-        .find(|(_, s)| s.is_some())
-        .map(|(y, s)| Coord { y, x: s.unwrap() })
-        .unwrap();
-    let e = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| (y, line.find('E')))
-        .find(|(_, e)| e.is_some())
-        .map(|(y, e)| Coord { y, x: e.unwrap() })
-        .unwrap();
-    (s, e)
+fn parse(input: &str) -> Result<(Grid<u8>, Coord, Coord), ParseError> {
+    let mut grid = Grid::parse(input, |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c as u8)
+    })?;
+    let mut find = |mark: u8, elevation: u8| {
+        if grid.iter().filter(|(_, square)| **square == mark).count() != 1 {
+            return Err(ParseError::new(
+                input,
+                &input[input.len()..],
+                format!("expected exactly one {}", mark as char),
+            ));
+        }
+        let (x, y) = grid.position(|square| *square == mark).unwrap();
+        grid[(x, y)] = elevation;
+        Ok(Coord { x, y })
+    };
+    let start = find(b'S', b'a')?;
+    let end = find(b'E', b'z')?;
+    Ok((grid, start, end))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "Sabqponm
 abcryxxl
@@ -135,9 +125,10 @@ abdefghi";
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 31);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 29);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day12::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(31));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(29));
+        println!("{}", solution_b(&input).unwrap());
     }
 }
@@ -0,0 +1,30 @@
+//! Pairs of packets, `size` counts the pairs.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day13;
+
+impl Generate for Day13 {
+    const SIZE: usize = 150;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", packet(4, rng), packet(4, rng)))
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+/// A list nested at most `depth` deep.
+fn packet(depth: u32, rng: &mut impl Rng) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth > 1 && rng.gen_bool(0.3) {
+                packet(depth - 1, rng)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
@@ -1,40 +1,68 @@
+mod generate;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{self, newline},
     combinator::map,
+    error::{Error, ErrorKind},
     multi::{separated_list0, separated_list1},
     sequence::{delimited, pair, separated_pair},
     IResult,
 };
-use std::cmp::Ordering;
+use solver::{parse, ParseError, Solver};
+use std::{cmp::Ordering, fmt::Display};
 use Packet::*;
 
 #[derive(Debug, PartialEq, Clone, Eq)]
-enum Packet {
+pub enum Packet {
     Val(u8),
     List(Vec<Packet>),
 }
 
 impl Ord for Packet {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        match (self, other) {
+            (Val(l), Val(r)) => l.cmp(r),
+            (Val(l), List(_)) => List(vec![Val(*l)]).cmp(other),
+            (List(_), Val(r)) => self.cmp(&List(vec![Val(*r)])),
+            (List(l), List(r)) => l.cmp(r),
+        }
     }
 }
 
 impl PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Val(l), Val(r)) => l.partial_cmp(r),
-            (Val(l), List(_)) => List(vec![Val(*l)]).partial_cmp(other),
-            (List(_), Val(r)) => self.partial_cmp(&List(vec![Val(*r)])),
-            (List(l), List(r)) => l.partial_cmp(r),
-        }
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Parsed<'a> = Vec<(Packet, Packet)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::finish(input, parse_a(input))
+    }
+
+    fn part_a(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(pairs))
     }
+
+    fn part_b(pairs: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(flatten(pairs)))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    let pairs = parse::finish(input, parse_a(input))?;
+    Ok(part_a(&pairs))
 }
 
-pub fn solution_a(input: &str) -> usize {
-    let (_, pairs) = parse_a(input).unwrap();
+fn part_a(pairs: &[(Packet, Packet)]) -> usize {
     let mut answer = 0;
     for (i, pair) in pairs.iter().enumerate() {
         let order = pair.0.partial_cmp(&pair.1);
@@ -45,8 +73,19 @@ pub fn solution_a(input: &str) -> usize {
     answer
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let (_, mut packets) = parse_b(input.replace("\n\n", "\n").as_str()).unwrap();
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    let pairs = parse::finish(input, parse_a(input))?;
+    Ok(part_b(flatten(&pairs)))
+}
+
+fn flatten(pairs: &[(Packet, Packet)]) -> Vec<Packet> {
+    pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect()
+}
+
+fn part_b(mut packets: Vec<Packet>) -> usize {
     let (_, dividers) = parse_b(
         "[[2]]
 [[6]]
@@ -61,11 +100,24 @@ pub fn solution_b(input: &str) -> usize {
     (first + 1) * (second + 1)
 }
 
+/// Lists nested deeper than this are rejected instead of recursing until the stack overflows.
+const MAX_DEPTH: usize = 64;
+
 fn parse_packet(input: &str) -> IResult<&str, Packet> {
+    parse_list(input, MAX_DEPTH)
+}
+
+fn parse_list(input: &str, depth: usize) -> IResult<&str, Packet> {
+    if depth == 0 {
+        return Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge)));
+    }
     delimited(
         tag("["),
         map(
-            separated_list0(tag(","), alt((map(complete::u8, Val), parse_packet))),
+            separated_list0(
+                tag(","),
+                alt((map(complete::u8, Val), |input| parse_list(input, depth - 1))),
+            ),
             List,
         ),
         tag("]"),
@@ -87,6 +139,7 @@ fn parse_b(input: &str) -> IResult<&str, Vec<Packet>> {
 mod tests {
 
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "[1,1,3,1,1]
 [1,1,5,1,1]
@@ -116,9 +169,15 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 13);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 140);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day13::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(13));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(140));
+        println!("{}", solution_b(&input).unwrap());
+
+        let deep = "[".repeat(100_000) + &"]".repeat(100_000);
+        let err = solution_a(&format!("{deep}\n[]")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, MAX_DEPTH + 1));
+        assert_eq!(err.message, "nested too deeply");
     }
 }
@@ -0,0 +1,30 @@
+//! A scan of rock paths under the sand source at 500,0, `size` counts the paths. Paths can close
+//! in the source so that no sand falls off, part A then counts the sand until it reaches the source.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day14;
+
+impl Generate for Day14 {
+    const SIZE: usize = 155;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.gen_range(470..=530), rng.gen_range(13..=170));
+                let mut path = vec![format!("{x},{y}")];
+                let horizontal = rng.gen_bool(0.5);
+                for i in 0..rng.gen_range(1..=6) {
+                    // turn at every point, the sand piles up around the rocks from the top
+                    if (i % 2 == 0) == horizontal {
+                        x = (x + rng.gen_range(-10..=10)).clamp(440, 560);
+                    } else {
+                        y = (y + rng.gen_range(-10..=10)).clamp(5, 175);
+                    }
+                    path.push(format!("{x},{y}"));
+                }
+                path.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
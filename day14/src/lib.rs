@@ -1,50 +1,94 @@
-use std::collections::HashSet;
+mod generate;
 
+use std::{collections::HashSet, fmt::Display};
+
+use geometry::{Dir8, Point};
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
     character::complete::{self, newline},
     combinator::map,
+    error::{Error, ErrorKind},
     multi::separated_list1,
     sequence::separated_pair,
     IResult,
 };
+use render::{Frame, Recorder, Visualise};
+use solver::{parse, ParseError, Solver};
+
+pub type Coord = Point<u32>;
+
+/// Largest coordinates of a rock, every point along a path is stored.
+const MAX_X: u32 = 1000;
+/// Sand spreads a tile sideways for each it falls from the source at x 500, it stays at x 0 or
+/// more down to the floor under this.
+const MAX_Y: u32 = 499;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    type Parsed<'a> = HashSet<Coord>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(rocks: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(rocks.clone(), None))
+    }
+
+    fn part_b(rocks: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(rocks.clone(), None))
+    }
+}
+
+/// A frame each time a unit of sand comes to rest.
+impl Visualise for Day14 {
+    fn visualise_a(rocks: &Self::Parsed<'_>, _: &(), recorder: &mut Recorder) -> impl Display {
+        part_a(rocks.clone(), Some(recorder))
+    }
+
+    fn visualise_b(
+        rocks: &Self::Parsed<'_>,
+        _: &(),
+        recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        Some(part_b(rocks.clone(), Some(recorder)))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(parse(input)?, None))
+}
 
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-struct Coord {
-    x: u32,
-    y: u32,
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(parse(input)?, None))
 }
 
-pub fn solution_a(input: &str) -> usize {
-    let mut rocks = parse(input);
+fn part_a(mut rocks: HashSet<Coord>, mut recorder: Option<&mut Recorder>) -> usize {
+    // sand is added to rocks as it comes to rest, keep the scan to tell them apart
+    let scan = match recorder {
+        Some(_) => rocks.clone(),
+        None => HashSet::new(),
+    };
     let floor = rocks.iter().map(|coord| coord.y).max().unwrap();
     let mut number_sands = 0;
     loop {
         let mut sand = Coord { x: 500, y: 0 };
         while sand.y <= floor {
             // try move down
-            if !rocks.contains(&Coord {
-                x: sand.x,
-                y: sand.y + 1,
-            }) {
-                sand.y += 1;
+            if !rocks.contains(&sand.step(Dir8::S)) {
+                sand = sand.step(Dir8::S);
             }
             // try move left down
-            else if !rocks.contains(&Coord {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            }) {
-                sand.x -= 1;
-                sand.y += 1;
+            else if !rocks.contains(&sand.step(Dir8::SW)) {
+                sand = sand.step(Dir8::SW);
             }
             // try move right down
-            else if !rocks.contains(&Coord {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            }) {
-                sand.x += 1;
-                sand.y += 1;
+            else if !rocks.contains(&sand.step(Dir8::SE)) {
+                sand = sand.step(Dir8::SE);
             }
             // cannot move
             else {
@@ -56,12 +100,22 @@ pub fn solution_a(input: &str) -> usize {
         if sand.y > floor {
             break;
         }
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.frame(|| draw(&scan, &rocks, None));
+        }
+        // rocks closed in the source, the sand piled up to it
+        if sand.x == 500 && sand.y == 0 {
+            break;
+        }
     }
     number_sands
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let mut rocks = parse(input);
+fn part_b(mut rocks: HashSet<Coord>, mut recorder: Option<&mut Recorder>) -> usize {
+    let scan = match recorder {
+        Some(_) => rocks.clone(),
+        None => HashSet::new(),
+    };
     let mut number_sands = 0;
 
     let floor = rocks.iter().map(|coord| coord.y).max().unwrap() + 2;
@@ -69,27 +123,16 @@ pub fn solution_b(input: &str) -> usize {
         let mut sand = Coord { x: 500, y: 0 };
         loop {
             // try move down
-            if !rocks.contains(&Coord {
-                x: sand.x,
-                y: sand.y + 1,
-            }) {
-                sand.y += 1;
+            if !rocks.contains(&sand.step(Dir8::S)) {
+                sand = sand.step(Dir8::S);
             }
             // try move left down
-            else if !rocks.contains(&Coord {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            }) {
-                sand.x -= 1;
-                sand.y += 1;
+            else if !rocks.contains(&sand.step(Dir8::SW)) {
+                sand = sand.step(Dir8::SW);
             }
             // try move right down
-            else if !rocks.contains(&Coord {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            }) {
-                sand.x += 1;
-                sand.y += 1;
+            else if !rocks.contains(&sand.step(Dir8::SE)) {
+                sand = sand.step(Dir8::SE);
             }
             // cannot move
             else {
@@ -106,6 +149,9 @@ pub fn solution_b(input: &str) -> usize {
             }
         }
 
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.frame(|| draw(&scan, &rocks, Some(floor)));
+        }
         if sand.x == 500 && sand.y == 0 {
             break;
         }
@@ -113,24 +159,70 @@ pub fn solution_b(input: &str) -> usize {
     number_sands
 }
 
-fn parse(input: &str) -> HashSet<Coord> {
-    let (_, coords) = parse_coords(input).unwrap();
-    coords
+/// The cave like the puzzle draws it, down to the lowest rock or to the `floor` of part B.
+fn draw(scan: &HashSet<Coord>, rocks: &HashSet<Coord>, floor: Option<u32>) -> Frame {
+    let (x_min, x_max, y_max) = match floor {
+        // the sand piles up at 45 degrees, it can't spread further than the floor is deep
+        Some(floor) => (500 - floor, 500 + floor, floor),
+        None => (
+            scan.iter().map(|coord| coord.x).min().unwrap() - 1,
+            scan.iter().map(|coord| coord.x).max().unwrap() + 1,
+            scan.iter().map(|coord| coord.y).max().unwrap(),
+        ),
+    };
+    let mut frame = Frame::new((x_max - x_min + 1) as usize, y_max as usize + 1, '.');
+    frame[((500 - x_min) as usize, 0)] = '+';
+    for coord in rocks {
+        if (x_min..=x_max).contains(&coord.x) && coord.y <= y_max {
+            frame[((coord.x - x_min) as usize, coord.y as usize)] =
+                if scan.contains(coord) { '#' } else { 'o' };
+        }
+    }
+    if floor.is_some() {
+        for x in 0..frame.width() {
+            frame[(x, y_max as usize)] = '#';
+        }
+    }
+    frame
+}
+
+fn parse(input: &str) -> Result<HashSet<Coord>, ParseError> {
+    let coords = match parse_coords(input) {
+        Err(nom::Err::Failure(err)) if err.code == ErrorKind::Verify => {
+            return Err(ParseError::new(
+                input,
+                err.input,
+                format!("expected x up to {MAX_X} and y up to {MAX_Y}"),
+            ));
+        }
+        result => parse::finish(input, result)?,
+    };
+    let rocks = coords
         .iter()
         .flat_map(|path| {
-            path.iter().tuple_windows().flat_map(|(from, to)| {
+            // a path of one point is a single rock
+            let path = path.iter().chain(path.last());
+            path.tuple_windows().flat_map(|(from, to)| {
                 if from.x == to.x {
-                    (from.y.min(to.y)..from.y.max(to.y) + 1)
+                    (from.y.min(to.y)..=from.y.max(to.y))
                         .map(|y| Coord { x: from.x, y })
                         .collect::<Vec<Coord>>()
                 } else {
-                    (from.x.min(to.x)..from.x.max(to.x) + 1)
+                    (from.x.min(to.x)..=from.x.max(to.x))
                         .map(|x| Coord { x, y: from.y })
                         .collect::<Vec<Coord>>()
                 }
             })
         })
-        .collect::<HashSet<Coord>>()
+        .collect::<HashSet<Coord>>();
+    if rocks.contains(&Coord { x: 500, y: 0 }) {
+        return Err(ParseError::new(
+            input,
+            input,
+            "rock covers the sand source at 500,0",
+        ));
+    }
+    Ok(rocks)
 }
 
 fn parse_coords(input: &str) -> IResult<&str, Vec<Vec<Coord>>> {
@@ -141,15 +233,27 @@ fn parse_line(input: &str) -> IResult<&str, Vec<Coord>> {
     separated_list1(
         tag(" -> "),
         map(
-            separated_pair(complete::u32, tag(","), complete::u32),
+            separated_pair(parse_coord(MAX_X), tag(","), parse_coord(MAX_Y)),
             |(x, y)| Coord { x, y },
         ),
     )(input)
 }
 
+/// A coordinate up to `max`, failing outright above it rather than ending the path there.
+fn parse_coord(max: u32) -> impl Fn(&str) -> IResult<&str, u32> {
+    move |input| {
+        let (rest, n) = complete::u32(input)?;
+        if n > max {
+            return Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify)));
+        }
+        Ok((rest, n))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const INPUT: &str = include_str!("input.txt");
     const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6
@@ -157,9 +261,20 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 24);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 93);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day14::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(24));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(93));
+        println!("{}", solution_b(&input).unwrap());
+        let err = parse("0,0 -> 4000000000,0").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 8));
+        assert!(parse("0,4294967295 -> 0,4294967295").is_err());
+        assert!(parse("500,500").is_err());
+        assert_eq!(
+            parse("1000,0 -> 1000,499").map(|rocks| rocks.len()),
+            Ok(500)
+        );
+        assert_eq!(solution_a("498,2 -> 502,2"), Ok(4));
+        assert_eq!(solution_a("500,5"), Ok(0));
     }
 }
@@ -0,0 +1,39 @@
+//! Sensors around a distress beacon within 0 to 4000000, `size` counts the sensors.
+
+use generator::{rand::Rng, Generate};
+
+use crate::{Coord, Day15, Params};
+
+impl Generate for Day15 {
+    const SIZE: usize = 28;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let Params { row, max } = Params::default();
+        let distress = Coord::new(rng.gen_range(0..=max), rng.gen_range(0..=max));
+        (0..size.max(1))
+            .map(|i| {
+                // the first sensor is on the row part A counts, so something is covered there
+                let y = if i == 0 { row } else { rng.gen_range(0..=max) };
+                let sensor = loop {
+                    let sensor = Coord::new(rng.gen_range(0..=max), y);
+                    if sensor != distress {
+                        break sensor;
+                    }
+                };
+                // each sensor reaches just short of the distress beacon, so it's out of range of
+                // them all and they cover as much of the area around it as they can
+                let radius = sensor.manhattan(distress) - 1;
+                let dx = rng.gen_range(0..=radius);
+                let dy = radius - dx;
+                let beacon = Coord::new(
+                    sensor.x + if rng.gen_bool(0.5) { dx } else { -dx },
+                    sensor.y + if rng.gen_bool(0.5) { dy } else { -dy },
+                );
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect()
+    }
+}
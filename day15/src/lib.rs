@@ -1,102 +1,114 @@
-#![feature(iter_array_chunks)]
-use std::collections::HashSet;
+mod generate;
 
-use itertools::Itertools;
+use std::{collections::HashSet, fmt::Display};
 
+use geometry::Point;
+use interval::{Interval, IntervalSet};
 use regex::Regex;
+use solver::{parse, ParseError, Solver};
+use tracing::trace;
 
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-struct Coord {
-    x: i32,
-    y: i32,
+pub type Coord = Point<i32>;
+
+pub struct Day15;
+
+pub struct Params {
+    /// Row to count covered positions in for part A.
+    pub row: i32,
+    /// Upper bound of both coordinates of the distress beacon for part B.
+    pub max: i32,
 }
 
-impl Coord {
-    fn manhat_dist(&self, other: &Self) -> u32 {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            max: 4000000,
+        }
+    }
+}
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    type Parsed<'a> = Vec<(Coord, Coord)>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
     }
+
+    fn part_a(pairs: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(part_a(pairs, params.row))
+    }
+
+    fn part_b(pairs: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        part_b(pairs, params.max)
+    }
+}
+
+pub fn solution_a(input: &str, y: i32) -> Result<usize, ParseError> {
+    Ok(part_a(&parse(input)?, y))
 }
 
-pub fn solution_a(input: &str, y: i32) -> usize {
-    let pairs = parse(input);
+pub fn solution_b(input: &str, max: i32) -> Result<Option<i128>, ParseError> {
+    Ok(part_b(&parse(input)?, max))
+}
+
+/// Positions on row `y` the sensors cover where the beacon can't be.
+fn part_a(pairs: &[(Coord, Coord)], y: i32) -> usize {
+    let covered = covered(pairs, y);
+    // every beacon on the row is in its own sensor's range
+    let beacons: HashSet<_> = pairs
+        .iter()
+        .filter(|(_, beacon)| beacon.y == y)
+        .map(|(_, beacon)| beacon.x)
+        .collect();
+    trace!(covered = covered.len(), beacons = beacons.len());
+    covered.len() as usize - beacons.len()
+}
+
+/// Tuning frequency of the one position with both coordinates in `0..=max` that no sensor covers.
+fn part_b(pairs: &[(Coord, Coord)], max: i32) -> Option<i128> {
+    let within = Interval::new(0, max)?;
+    (0..=max).find_map(|y| {
+        let gap = *covered(pairs, y).gaps(within).intervals().first()?;
+        Some(gap.start() as i128 * 4000000 + y as i128)
+    })
+}
+
+/// Positions on row `y` in range of a sensor.
+fn covered(pairs: &[(Coord, Coord)], y: i32) -> IntervalSet<i32> {
     pairs
         .iter()
         .filter_map(|(sensor, beacon)| {
-            let manhat = sensor.manhat_dist(beacon);
-            let y_dist = sensor.y.abs_diff(y);
-            if manhat >= y_dist {
-                let radius = manhat - y_dist;
-                let mut positions =
-                    HashSet::<i32>::from_iter(sensor.x - radius as i32..=sensor.x + radius as i32);
-                if beacon.y == y {
-                    positions.remove(&beacon.x);
-                }
-                println!("{:?}", positions.len());
-                Some(positions)
-            } else {
-                None
-            }
+            let radius = sensor.manhattan(*beacon) - (sensor.y - y).abs();
+            Interval::new(sensor.x - radius, sensor.x + radius)
         })
-        .reduce(|acc, e| acc.union(&e).copied().collect())
-        .unwrap()
-        .len()
-}
-
-pub fn solution_b(input: &str, max: i32) -> i128 {
-    let pairs = parse(input);
-    for y in 0..max {
-        let mut ranges: Vec<_> = pairs
-            .iter()
-            .filter_map(|(sensor, beacon)| {
-                let manhat = sensor.manhat_dist(beacon);
-                let y_dist = sensor.y.abs_diff(y);
-                if manhat >= y_dist {
-                    let radius = manhat - y_dist;
-                    Some(sensor.x - radius as i32..=sensor.x + radius as i32)
-                } else {
-                    None
-                }
-            })
-            .collect();
-        ranges.sort_by_key(|r| *r.start());
-
-        // println!("{:?}", ranges);
-        let mut end = 0;
-        for r in ranges {
-            if r.contains(&end) {
-                end = *r.end();
-                continue;
-            }
-            if end + 2 == *r.start() {
-                println!(
-                    "{:?} {} {}",
-                    end + 1,
-                    y,
-                    (end as i128 + 1) * 4000000 + y as i128
-                );
-                // return (end as i128 + 1) * 4000000 + y as i128;
-            }
-        }
-    }
-    1
+        .collect()
 }
 
-fn parse(input: &str) -> Vec<(Coord, Coord)> {
-    let re = Regex::new(r"\d+").unwrap();
-    re.find_iter(input)
-        .map(|m| m.as_str().parse::<i32>().unwrap())
-        .array_chunks::<4>()
-        .map(|chunk| {
-            (
-                Coord {
-                    x: chunk[0],
-                    y: chunk[1],
-                },
-                Coord {
-                    x: chunk[2],
-                    y: chunk[3],
-                },
-            )
+fn parse(input: &str) -> Result<Vec<(Coord, Coord)>, ParseError> {
+    let re =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+    input
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let Some(cap) = re.captures(line) else {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "expected a sensor and its closest beacon",
+                ));
+            };
+            let coord = |x: usize, y: usize| -> Result<Coord, ParseError> {
+                Ok(Coord {
+                    x: parse::number(input, cap.get(x).unwrap().as_str())?,
+                    y: parse::number(input, cap.get(y).unwrap().as_str())?,
+                })
+            };
+            Ok((coord(1, 2)?, coord(3, 4)?))
         })
         .collect()
 }
@@ -104,6 +116,7 @@ fn parse(input: &str) -> Vec<(Coord, Coord)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const INPUT: &str = include_str!("input.txt");
     const TEST_INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
@@ -123,9 +136,10 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT, 10), 26);
-        println!("{}", solution_a(INPUT, 2000000));
-        println!("{}", solution_b(TEST_INPUT, 20));
-        println!("{}", solution_b(INPUT, 4000000));
+        let input = input::or_embedded(Day15::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT, 10), Ok(26));
+        println!("{}", solution_a(&input, 2000000).unwrap());
+        assert_eq!(solution_b(TEST_INPUT, 20), Ok(Some(56000011)));
+        println!("{}", solution_b(&input, 4000000).unwrap().unwrap());
     }
 }
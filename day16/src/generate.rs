@@ -0,0 +1,58 @@
+//! A network of valves and tunnels, `size` counts the valves.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day16;
+
+impl Generate for Day16 {
+    const SIZE: usize = 51;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let size = size.clamp(2, 26 * 26);
+        let mut names = vec!["AA".to_string()];
+        while names.len() < size {
+            let name: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        // a random tree keeps every valve reachable, a few more tunnels make loops in it
+        let mut tunnels = vec![vec![]; size];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..size {
+            connect(valve, rng.gen_range(0..valve));
+        }
+        for _ in 0..size / 2 {
+            connect(rng.gen_range(0..size), rng.gen_range(0..size));
+        }
+
+        (0..size)
+            .map(|valve| {
+                // most valves are stuck, the one in AA always is
+                let flow_rate = match valve {
+                    0 => 0,
+                    _ if rng.gen_bool(0.3) => rng.gen_range(3..=25),
+                    _ => 0,
+                };
+                let leads: Vec<&str> = tunnels[valve]
+                    .iter()
+                    .map(|other| names[*other].as_str())
+                    .collect();
+                let tunnels = match leads.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={flow_rate}; {tunnels} {}\n",
+                    names[valve],
+                    leads.join(", ")
+                )
+            })
+            .collect()
+    }
+}
@@ -1,12 +1,15 @@
+mod generate;
+
 use std::{
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     fmt,
 };
 
 use regex::Regex;
+use solver::{parse, ParseError, Solver};
 
 #[derive(Hash, Debug, Eq, PartialEq, Clone)]
-struct Valve {
+pub struct Valve {
     name: Name,
     flow_rate: u32,
     lead_to: Vec<Name>,
@@ -28,200 +31,139 @@ impl fmt::Debug for Name {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-struct TimeState {
-    cost: u32,
-    valve: Name,
-}
+pub struct Day16;
 
-impl Ord for TimeState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.cost.cmp(&self.cost)
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    type Parsed<'a> = Vec<Valve>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
     }
-}
 
-impl PartialOrd for TimeState {
-    // This is a min-heap, so we want the smallest element on top
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    fn part_a(valves: &Self::Parsed<'_>, _: &()) -> Option<impl fmt::Display> {
+        Some(part_a(valves))
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
-struct FlowState {
-    flow: u32,
-    flow_rate: u32,
-    time_remain: u32,
-    valve: Name,
-    visited: HashSet<Name>,
+pub fn solution_a(input: &str) -> Result<u64, ParseError> {
+    Ok(part_a(&parse(input)?))
 }
 
-impl Ord for FlowState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.flow.cmp(&other.flow)
-    }
+/// Most pressure released in 30 minutes, trying every order to open the valves that release any.
+fn part_a(valves: &[Valve]) -> u64 {
+    let graph: HashMap<Name, &Valve> = valves.iter().map(|valve| (valve.name, valve)).collect();
+    let useful: Vec<&Valve> = valves.iter().filter(|valve| valve.flow_rate > 0).collect();
+    let times_from = |from: Name| {
+        let times = walking_times(&graph, from);
+        useful
+            .iter()
+            .map(|valve| times.get(&valve.name).copied())
+            .collect::<Vec<_>>()
+    };
+    let start = times_from(Name::new("AA"));
+    let between: Vec<_> = useful.iter().map(|valve| times_from(valve.name)).collect();
+    most_pressure(
+        &useful,
+        &between,
+        &start,
+        30,
+        &mut vec![false; useful.len()],
+    )
 }
 
-impl PartialOrd for FlowState {
-    // This is a max-heap, so we want the biggest element on top
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(other.cmp(self))
-    }
-}
-
-fn shortest_time_path(
-    graph: &HashMap<Name, Valve>,
-    start: Name,
-    end: Name,
-    remaining_time: u32,
-    visited: &HashSet<Name>,
-) -> Option<(u32, Vec<Name>)> {
-    let mut dist: HashMap<Name, u32> =
-        HashMap::from_iter(graph.keys().map(|valve| (*valve, std::u32::MAX)));
-    let mut heap = BinaryHeap::<TimeState>::new();
-    let mut prev = HashMap::<Name, Name>::new();
-    // we're at start, with a zero cost
-    dist.entry(start).and_modify(|d| *d = 0);
-    heap.push(TimeState {
-        cost: 0,
-        valve: start,
-    });
-
-    // examine the frontier with lower cost first (min-heap)
-    while let Some(TimeState { cost, valve }) = heap.pop() {
-        if valve == end {
-            if cost > remaining_time {
-                return None;
-            } else {
-                let mut path = vec![];
-                let mut cur = end;
-                path.push(cur);
-                loop {
-                    if cur == start {
-                        break;
-                    }
-                    cur = prev[&cur];
-                    path.push(cur);
-                }
-                path.reverse();
-                return Some((cost, path));
-            }
-        }
-        if cost > dist[&valve] {
-            continue;
-        }
-
-        for edge in &graph[&valve].lead_to {
-            let is_visited = visited.contains(edge);
-            let next = TimeState {
-                cost: cost + if is_visited { 1 } else { 2 },
-                valve: *edge,
-            };
-            if next.cost < dist[&next.valve] {
-                heap.push(next);
-                prev.entry(next.valve)
-                    .and_modify(|p| *p = *edge)
-                    .or_insert(valve);
-                dist.entry(next.valve).and_modify(|d| *d = next.cost);
+/// Minutes to walk from `from` to every valve it leads to.
+fn walking_times(graph: &HashMap<Name, &Valve>, from: Name) -> HashMap<Name, u32> {
+    let mut times = HashMap::from([(from, 0)]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(valve) = queue.pop_front() {
+        let time = times[&valve];
+        for next in &graph[&valve].lead_to {
+            if !times.contains_key(next) {
+                times.insert(*next, time + 1);
+                queue.push_back(*next);
             }
         }
     }
-    // Goal not reachable
-    None
+    times
 }
 
-// at a point
-// find shortest path to all valve with positive flow rates
-// for each valve, calculate how much time we would need to move to it, and how much flow we would
-// gain with the remaining time we have
-// the higher the flow gain, the lower the cost
-// do the dijkstra thing
-// update the graph to reflect which valve is open
-//
-pub fn solution_a(input: &str) -> u32 {
-    let valves = parse(input);
-
-    let graph: HashMap<Name, Valve> =
-        HashMap::from_iter(valves.iter().map(|v| (v.name, v.clone())));
-
-    let mut flows: HashMap<Name, u32> =
-        HashMap::from_iter(graph.keys().map(|valve| (*valve, std::u32::MIN)));
-    let mut heap = BinaryHeap::<FlowState>::new();
-
-    let start = Name::new("AA");
-    // we're at start, with a zero cost
-    flows.entry(start).and_modify(|d| *d = 0);
-    heap.push(FlowState {
-        flow: 0,
-        flow_rate: 0,
-        time_remain: 30,
-        valve: start,
-        visited: HashSet::new(),
-    });
-    let mut results = vec![];
-    // get frontier with highest flow (max heap)
-    while let Some(FlowState {
-        flow,
-        time_remain,
-        flow_rate,
-        valve: from,
-        visited,
-    }) = heap.pop()
-    {
-        if time_remain == 0 {
-            results.push(flow);
+/// Most pressure the valves not `opened` yet can release in `time_remain` minutes, `times` being
+/// how long it takes to walk to each of them.
+fn most_pressure(
+    useful: &[&Valve],
+    between: &[Vec<Option<u32>>],
+    times: &[Option<u32>],
+    time_remain: u32,
+    opened: &mut [bool],
+) -> u64 {
+    let mut best = 0;
+    for (i, valve) in useful.iter().enumerate() {
+        let Some(time) = times[i] else {
             continue;
-        }
-
-        // we want flow to be as high as possible, so skip if flow is lower
-        if flow < flows[&from] {
+        };
+        // walking there and opening it has to leave a minute for the pressure to be released
+        if opened[i] || time + 1 >= time_remain {
             continue;
         }
-
-        for to in &graph[&from].lead_to {
-            let shit = shortest_time_path(&graph, from, *to, time_remain, &visited);
-            if shit.is_none() {
-                continue;
-            }
-            let (time_spent, _) = shit.unwrap();
-            let mut visited = visited.clone();
-            visited.insert(*to);
-            let next = FlowState {
-                flow: flow + time_spent * flow_rate,
-                time_remain: time_remain - time_spent,
-                flow_rate: graph
-                    .iter()
-                    .filter(|(k, _)| visited.contains(k))
-                    .map(|(_, v)| v.flow_rate)
-                    .sum(),
-                valve: *to,
-                visited,
-            };
-            if next.flow >= flows[&next.valve] {
-                heap.push(next.clone());
-                flows.entry(next.valve).and_modify(|d| *d = next.flow);
-            }
-        }
+        let time_remain = time_remain - time - 1;
+        opened[i] = true;
+        let pressure = u64::from(valve.flow_rate) * u64::from(time_remain)
+            + most_pressure(useful, between, &between[i], time_remain, opened);
+        opened[i] = false;
+        best = best.max(pressure);
     }
-    // dbg!(heap);
-    println!("{:?}", results.iter().max());
-    0
+    best
 }
 
-fn parse(input: &str) -> Vec<Valve> {
-    let re = Regex::new(r"Valve ([A-Z]{2}) .*=(\d+).*valves? (.*)").unwrap();
-    re.captures_iter(input)
-        .map(|cap| Valve {
-            name: Name::new(&cap[1]),
-            flow_rate: cap[2].parse().unwrap(),
-            lead_to: cap[3].split(", ").map(Name::new).collect(),
+fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let re = Regex::new(
+        r"^Valve ([A-Z]{2}) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z]{2}(?:, [A-Z]{2})*)$",
+    )
+    .unwrap();
+    let valves = input
+        .lines()
+        .map(|line| {
+            let Some(cap) = re.captures(line) else {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "expected a valve, its flow rate and tunnels",
+                ));
+            };
+            Ok(Valve {
+                name: Name::new(&cap[1]),
+                flow_rate: parse::number(input, cap.get(2).unwrap().as_str())?,
+                lead_to: cap[3].split(", ").map(Name::new).collect(),
+            })
         })
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let names: HashSet<Name> = valves.iter().map(|valve| valve.name).collect();
+    for (line, valve) in input.lines().zip(&valves) {
+        if let Some(unknown) = valve.lead_to.iter().find(|name| !names.contains(name)) {
+            return Err(ParseError::new(
+                input,
+                line,
+                format!("tunnel to unknown valve {unknown:?}"),
+            ));
+        }
+    }
+    if !names.contains(&Name::new("AA")) {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a valve AA to start from",
+        ));
+    }
+    Ok(valves)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
@@ -234,8 +176,12 @@ Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
 
+    const INPUT: &str = include_str!("input.txt");
+
     #[test]
     fn it_works() {
-        println!("{}", solution_a(TEST_INPUT));
+        let input = input::or_embedded(Day16::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(1651));
+        println!("{}", solution_a(&input).unwrap());
     }
 }
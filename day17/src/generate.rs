@@ -0,0 +1,17 @@
+//! A pattern of jets, `size` counts them.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    const SIZE: usize = 10091;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut jets: String = (0..size.max(1))
+            .map(|_| if rng.gen_bool(0.5) { '<' } else { '>' })
+            .collect();
+        jets.push('\n');
+        jets
+    }
+}
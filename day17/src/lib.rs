@@ -1,30 +1,106 @@
-use hashbrown::HashSet;
-use itertools::Itertools;
+mod generate;
+
+use std::fmt::Display;
+
+use hashbrown::{HashMap, HashSet};
+use render::{Frame, Recorder, Visualise};
+use solver::{ParseError, Solver};
 use Movement::*;
 
-pub fn solution(input: &str, simulation_steps: usize, total_steps: usize) -> u64 {
-    let movements = parse(input);
-    let mut rocks = HashSet::<(u64, u64)>::with_capacity(simulation_steps);
-    let mut spawn_funcs = [
+pub struct Day17;
+
+pub struct Params {
+    pub total_steps_a: usize,
+    pub total_steps_b: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            total_steps_a: 2022,
+            total_steps_b: 1000000000000,
+        }
+    }
+}
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+    type Parsed<'a> = Vec<Movement>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(movements: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(simulate(movements, params.total_steps_a, None))
+    }
+
+    fn part_b(movements: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(simulate(movements, params.total_steps_b, None))
+    }
+}
+
+/// A frame each time the falling rock is pushed or falls. Both parts simulate the same rocks,
+/// the height after that is extrapolated.
+impl Visualise for Day17 {
+    fn visualise_a(
+        movements: &Self::Parsed<'_>,
+        params: &Params,
+        recorder: &mut Recorder,
+    ) -> impl Display {
+        simulate(movements, params.total_steps_a, Some(recorder))
+    }
+
+    fn visualise_b(
+        movements: &Self::Parsed<'_>,
+        params: &Params,
+        recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        Some(simulate(movements, params.total_steps_b, Some(recorder)))
+    }
+}
+
+pub fn solution(input: &str, total_steps: usize) -> Result<u64, ParseError> {
+    Ok(simulate(&parse(input)?, total_steps, None))
+}
+
+/// Drops rocks until the next rock, jet and surface of the tower are the same as after an earlier
+/// rock, from then on the tower grows the same way over and over.
+fn simulate(
+    movements: &[Movement],
+    total_steps: usize,
+    mut recorder: Option<&mut Recorder>,
+) -> u64 {
+    let mut rocks = HashSet::<(u64, u64)>::new();
+    let spawn_funcs = [
         spawn_horizontal,
         spawn_cross,
         spawn_l,
         spawn_vertical,
         spawn_square,
-    ]
-    .iter()
-    .cycle();
-    let mut movements = movements.iter().cycle();
+    ];
+    let mut movement = 0;
     let mut y_max = 0;
-    let mut tetris_stack = Vec::with_capacity(simulation_steps);
-    for _ in 0..simulation_steps {
-        let spawn_func = spawn_funcs.next().unwrap();
-        let mut tetris = spawn_func(y_max);
-        // visualise(&rocks, &tetris);
+    // height of the tower after each rock, and the rock each state was first seen before
+    let mut heights = vec![0];
+    let mut seen = HashMap::new();
+    for step in 0..total_steps {
+        let surface = surface(&rocks, y_max);
+        if let Some(first) = seen.insert((step % spawn_funcs.len(), movement, surface), step) {
+            let cycle = step - first;
+            let repeats = (total_steps - first) / cycle;
+            let left_over = (total_steps - first) % cycle;
+            return heights[first + left_over] + repeats as u64 * (y_max - heights[first]);
+        }
+
+        let mut tetris = spawn_funcs[step % spawn_funcs.len()](y_max);
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.frame(|| draw(&rocks, &tetris, y_max));
+        }
         loop {
-            let movement = movements.next().unwrap();
             // move left or right
-            match movement {
+            match movements[movement] {
                 Left => {
                     let moved: Vec<_> = tetris.iter().map(|(x, y)| (*x - 1, *y)).collect();
                     if moved
@@ -44,7 +120,10 @@ pub fn solution(input: &str, simulation_steps: usize, total_steps: usize) -> u64
                     }
                 }
             }
-            // visualise(&rocks, &tetris);
+            movement = (movement + 1) % movements.len();
+            if let Some(recorder) = recorder.as_deref_mut() {
+                recorder.frame(|| draw(&rocks, &tetris, y_max));
+            }
             // fall down
             let moved: Vec<_> = tetris.iter().map(|(x, y)| (*x, *y - 1)).collect();
             if moved
@@ -55,51 +134,36 @@ pub fn solution(input: &str, simulation_steps: usize, total_steps: usize) -> u64
             } else {
                 break;
             }
-            // visualise(&rocks, &tetris);
+            if let Some(recorder) = recorder.as_deref_mut() {
+                recorder.frame(|| draw(&rocks, &tetris, y_max));
+            }
         }
-        for rock in &tetris {
-            rocks.insert(*rock);
+        for &(x, y) in &tetris {
+            rocks.insert((x, y));
+            y_max = y_max.max(y);
         }
-        y_max = y_max.max(*tetris.iter().map(|(_, y)| y).max().unwrap());
-        tetris_stack.push(tetris);
+        heights.push(y_max);
     }
+    y_max
+}
 
-    let repeat_segment_length = 30;
-    let repeat_segment = &tetris_stack[tetris_stack.len() - repeat_segment_length..];
-
-    let repeat_segment_positions: Vec<_> = tetris_stack
-        .windows(repeat_segment_length)
-        .positions(|window| {
-            window
-                .iter()
-                .zip(repeat_segment.iter())
-                .all(|(l, r)| l.iter().zip(r.iter()).all(|(l, r)| l.0 == r.0))
-        })
-        .take(2)
-        .collect();
-
-    let cummax: Vec<_> = tetris_stack
-        .iter()
-        .map(|tetris| tetris.iter().map(|(_, y)| y).max().unwrap())
-        .scan(0u64, |max, e| {
-            *max = (*max).max(*e);
-            Some(*max)
-        })
+/// Empty cells a falling rock can still get to, by depth below the row above the tower. Rocks
+/// only ever move left, right or down, so nothing under this changes how the tower grows.
+fn surface(rocks: &HashSet<(u64, u64)>, y_max: u64) -> Vec<(u64, u64)> {
+    let mut reached = HashSet::new();
+    let mut todo: Vec<_> = (1..8).map(|x| (x, y_max + 1)).collect();
+    while let Some((x, y)) = todo.pop() {
+        if x == 0 || x == 8 || y == 0 || rocks.contains(&(x, y)) || !reached.insert((x, y)) {
+            continue;
+        }
+        todo.extend([(x - 1, y), (x + 1, y), (x, y - 1)]);
+    }
+    let mut surface: Vec<_> = reached
+        .into_iter()
+        .map(|(x, y)| (x, y_max + 1 - y))
         .collect();
-
-    let first_repeat = repeat_segment_positions[0];
-    let second_repeat = repeat_segment_positions[1];
-
-    let cummax = &cummax[first_repeat - 1..second_repeat];
-
-    let cumdiff: Vec<_> = cummax.iter().map(|m| m - cummax[0]).collect();
-    let cumdiff = &cumdiff[1..];
-
-    let initial_height = cummax[0];
-    let number_repeats = (total_steps - first_repeat - 1) / cumdiff.len();
-    let final_height = initial_height + number_repeats as u64 * cumdiff[cumdiff.len() - 1];
-    let left_over_steps = (total_steps - first_repeat - 1) % cumdiff.len();
-    final_height + cumdiff[left_over_steps]
+    surface.sort_unstable();
+    surface
 }
 
 // 0 1 2 3 4 5 6 7 8
@@ -151,55 +215,49 @@ fn spawn_square(y_max: u64) -> Vec<(u64, u64)> {
     ]
 }
 
-fn visualise(rocks: &HashSet<(u64, u64)>, tetris: &[(u64, u64)]) {
-    let y_max = rocks
-        .iter()
-        .chain(tetris.iter())
-        .map(|(_, y)| y)
-        .max()
-        .unwrap();
-
-    let mut grid = vec![vec!['.'; 9]; *y_max as usize + 1];
+/// Rows of the chamber shown, following the top of the tower.
+const WINDOW: u64 = 30;
 
-    for y in 0..y_max + 1 {
-        grid[y as usize][0] = '|';
-        grid[y as usize][8] = '|';
-    }
-
-    for x in 0..9 {
-        grid[0][x] = '-';
-    }
-
-    for rock in rocks.iter() {
-        grid[rock.1 as usize][rock.0 as usize] = '#';
-    }
-    for rock in tetris.iter() {
-        grid[rock.1 as usize][rock.0 as usize] = '@';
+/// The top of the chamber like the puzzle draws it, `@` for the falling rock.
+fn draw(rocks: &HashSet<(u64, u64)>, tetris: &[(u64, u64)], y_max: u64) -> Frame {
+    // rocks spawn with their top at most 7 above the tower
+    let top = y_max + 7;
+    let mut frame = Frame::new(9, WINDOW as usize, ' ');
+    for row in 0..WINDOW.min(top + 1) {
+        let y = top - row;
+        for x in 0..9 {
+            frame[(x as usize, row as usize)] = if y == 0 {
+                '-'
+            } else if x == 0 || x == 8 {
+                '|'
+            } else if tetris.contains(&(x, y)) {
+                '@'
+            } else if rocks.contains(&(x, y)) {
+                '#'
+            } else {
+                '.'
+            };
+        }
     }
-
-    let plot = grid
-        .iter()
-        .rev()
-        .map(|line| line.iter().collect::<String>())
-        .collect::<Vec<String>>()
-        .join("\n");
-    println!("{plot}\n");
+    frame
 }
 
 #[derive(Debug)]
-enum Movement {
+pub enum Movement {
     Left,
     Right,
 }
 
-fn parse(input: &str) -> Vec<Movement> {
-    input
-        .trim()
-        .chars()
-        .map(|c| match c {
-            '>' => Right,
-            '<' => Left,
-            _ => unreachable!(),
+fn parse(input: &str) -> Result<Vec<Movement>, ParseError> {
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(ParseError::new(input, jets, "expected a jet pattern"));
+    }
+    jets.char_indices()
+        .map(|(i, c)| match c {
+            '>' => Ok(Right),
+            '<' => Ok(Left),
+            _ => Err(ParseError::new(input, &jets[i..], "expected > or <")),
         })
         .collect()
 }
@@ -207,15 +265,17 @@ fn parse(input: &str) -> Vec<Movement> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
     const INPUT: &str = include_str!("input.txt");
 
     #[test]
     fn it_works() {
-        assert_eq!(solution(TEST_INPUT, 80, 2022), 3068);
-        println!("{}", solution(INPUT, 1971, 2022));
-        assert_eq!(solution(TEST_INPUT, 80, 1000000000000), 1514285714288);
-        println!("{}", solution(INPUT, 1971, 1000000000000));
+        let input = input::or_embedded(Day17::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT, 2022), Ok(3068));
+        println!("{}", solution(&input, 2022).unwrap());
+        assert_eq!(solution(TEST_INPUT, 1000000000000), Ok(1514285714288));
+        println!("{}", solution(&input, 1000000000000).unwrap());
     }
 }
@@ -0,0 +1,46 @@
+//! A lump of lava with air pockets in it, `size` counts its cubes.
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::Day18;
+
+impl Generate for Day18 {
+    const SIZE: usize = 2136;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let size = size.max(1);
+        // about 60% of a ball, the rest of it is air that's partly trapped inside
+        let mut radius = (size as f64 / 0.6 * 3.0 / (4.0 * std::f64::consts::PI)).cbrt();
+        let mut ball = loop {
+            let ball = ball(radius);
+            if ball.len() >= size {
+                break ball;
+            }
+            radius += 1.0;
+        };
+        ball.shuffle(rng);
+        let centre = radius.ceil() as i32 + 1;
+        ball[..size]
+            .iter()
+            .map(|(x, y, z)| format!("{},{},{}\n", centre + x, centre + y, centre + z))
+            .collect()
+    }
+}
+
+fn ball(radius: f64) -> Vec<(i32, i32, i32)> {
+    let reach = radius.ceil() as i32;
+    let mut ball = vec![];
+    for x in -reach..=reach {
+        for y in -reach..=reach {
+            for z in -reach..=reach {
+                if ((x * x + y * y + z * z) as f64).sqrt() <= radius {
+                    ball.push((x, y, z));
+                }
+            }
+        }
+    }
+    ball
+}
@@ -1,5 +1,11 @@
-use std::collections::{HashSet, VecDeque};
+mod generate;
 
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use geometry::Point3;
 use nom::{
     bytes::complete::tag,
     character::complete::{self, newline},
@@ -8,39 +14,67 @@ use nom::{
     sequence::tuple,
     IResult,
 };
+use solver::{parse, ParseError, Solver};
+
+pub struct Day18;
 
-pub fn solution_a(input: &str) -> usize {
-    let (_, coords) = parse(input).unwrap();
-    let coords: HashSet<(i32, i32, i32)> = HashSet::from_iter(coords.into_iter());
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+    type Parsed<'a> = HashSet<Point3<i32>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let coords = parse::finish(input, parse(input))?;
+        Ok(HashSet::from_iter(coords))
+    }
+
+    fn part_a(coords: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(coords))
+    }
+
+    fn part_b(coords: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(coords))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    let coords = parse::finish(input, parse(input))?;
+    Ok(part_a(&HashSet::from_iter(coords)))
+}
+
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    let coords = parse::finish(input, parse(input))?;
+    Ok(part_b(&HashSet::from_iter(coords)))
+}
+
+fn part_a(coords: &HashSet<Point3<i32>>) -> usize {
     coords
         .iter()
-        .flat_map(|c| get_neighbours(*c))
+        .flat_map(|c| c.neighbours6())
         // for every coord, count neighbours that aren't in coords, i.e. is air
         .filter(|c| !coords.contains(c))
         .count()
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let (_, coords) = parse(input).unwrap();
-    let coords: HashSet<(i32, i32, i32)> = HashSet::from_iter(coords.into_iter());
-    let x_min = *coords.iter().map(|(x, _, _)| x).min().unwrap() - 1;
-    let x_max = *coords.iter().map(|(x, _, _)| x).max().unwrap() + 1;
-    let y_min = *coords.iter().map(|(_, y, _)| y).min().unwrap() - 1;
-    let y_max = *coords.iter().map(|(_, y, _)| y).max().unwrap() + 1;
-    let z_min = *coords.iter().map(|(_, _, z)| z).min().unwrap() - 1;
-    let z_max = *coords.iter().map(|(_, _, z)| z).max().unwrap() + 1;
+fn part_b(coords: &HashSet<Point3<i32>>) -> usize {
+    let x_min = coords.iter().map(|c| c.x).min().unwrap() - 1;
+    let x_max = coords.iter().map(|c| c.x).max().unwrap() + 1;
+    let y_min = coords.iter().map(|c| c.y).min().unwrap() - 1;
+    let y_max = coords.iter().map(|c| c.y).max().unwrap() + 1;
+    let z_min = coords.iter().map(|c| c.z).min().unwrap() - 1;
+    let z_max = coords.iter().map(|c| c.z).max().unwrap() + 1;
 
     let mut surface = HashSet::new();
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
-    queue.push_back((x_min, y_min, z_min));
+    queue.push_back(Point3::new(x_min, y_min, z_min));
     while let Some(c) = queue.pop_front() {
-        if c.0 < x_min
-            || c.0 > x_max
-            || c.1 < y_min
-            || c.1 > y_max
-            || c.2 < z_min
-            || c.2 > z_max
+        if c.x < x_min
+            || c.x > x_max
+            || c.y < y_min
+            || c.y > y_max
+            || c.z < z_min
+            || c.z > z_max
         {
             continue;
         }
@@ -49,7 +83,7 @@ pub fn solution_b(input: &str) -> usize {
             continue;
         }
 
-        for neighbour in get_neighbours(c) {
+        for neighbour in c.neighbours6() {
             if coords.contains(&neighbour) {
                 surface.insert(c);
                 continue;
@@ -59,24 +93,13 @@ pub fn solution_b(input: &str) -> usize {
     }
 
     surface.iter()
-        .flat_map(|c| get_neighbours(*c))
+        .flat_map(|c| c.neighbours6())
         // for every coord in surface, count neighbours that are in coord
         .filter(|c| coords.contains(c))
         .count()
 }
 
-fn get_neighbours(coord: (i32, i32, i32)) -> Vec<(i32, i32, i32)> {
-    vec![
-        (coord.0 + 1, coord.1, coord.2),
-        (coord.0 - 1, coord.1, coord.2),
-        (coord.0, coord.1 + 1, coord.2),
-        (coord.0, coord.1 - 1, coord.2),
-        (coord.0, coord.1, coord.2 + 1),
-        (coord.0, coord.1, coord.2 - 1),
-    ]
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<(i32, i32, i32)>> {
+fn parse(input: &str) -> IResult<&str, Vec<Point3<i32>>> {
     separated_list1(
         newline,
         map(
@@ -87,7 +110,7 @@ fn parse(input: &str) -> IResult<&str, Vec<(i32, i32, i32)>> {
                 tag(","),
                 complete::i32,
             )),
-            |(x, _, y, _, z)| (x, y, z),
+            |(x, _, y, _, z)| Point3::new(x, y, z),
         ),
     )(input)
 }
@@ -95,6 +118,7 @@ fn parse(input: &str) -> IResult<&str, Vec<(i32, i32, i32)>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "2,2,2
 1,2,2
@@ -114,9 +138,10 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 64);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 58);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day18::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(64));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(58));
+        println!("{}", solution_b(&input).unwrap());
     }
 }
@@ -0,0 +1,22 @@
+//! The encrypted file, `size` counts its numbers. Only one of them is 0.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day20;
+
+impl Generate for Day20 {
+    const SIZE: usize = 5000;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        let mut numbers: Vec<i64> = (1..size.max(2))
+            .map(|_| loop {
+                let number = rng.gen_range(-10000..=10000);
+                if number != 0 {
+                    break number;
+                }
+            })
+            .collect();
+        numbers.insert(rng.gen_range(0..=numbers.len()), 0);
+        numbers.iter().map(|number| format!("{number}\n")).collect()
+    }
+}
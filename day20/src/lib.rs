@@ -1,12 +1,54 @@
+mod generate;
+
+use std::fmt::Display;
+
 use nom::{
     character::complete::{self, newline},
     multi::separated_list1,
     IResult,
 };
+use solver::{parse, ParseError, Solver};
 use tracing::*;
 
-pub fn solution(input: &str, decryption_key: i64, n_mixes: usize) -> i64 {
-    let (_, numbers) = parse(input).unwrap();
+pub struct Day20;
+
+pub struct Params {
+    pub decryption_key: i64,
+    pub n_mixes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            decryption_key: 811589153,
+            n_mixes: 10,
+        }
+    }
+}
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+    type Parsed<'a> = Vec<i64>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn part_a(numbers: &Self::Parsed<'_>, _: &Params) -> Option<impl Display> {
+        Some(decrypt(numbers, 1, 1))
+    }
+
+    fn part_b(numbers: &Self::Parsed<'_>, params: &Params) -> Option<impl Display> {
+        Some(decrypt(numbers, params.decryption_key, params.n_mixes))
+    }
+}
+
+pub fn solution(input: &str, decryption_key: i64, n_mixes: usize) -> Result<i64, ParseError> {
+    Ok(decrypt(&parse_numbers(input)?, decryption_key, n_mixes))
+}
+
+fn decrypt(numbers: &[i64], decryption_key: i64, n_mixes: usize) -> i64 {
     let numbers: Vec<_> = numbers.iter().map(|n| n * decryption_key).collect();
     let mixed_numbers = mix_n(numbers, n_mixes);
     let zero_idx = mixed_numbers.iter().position(|n| *n == 0).unwrap();
@@ -31,6 +73,10 @@ fn mix_n(numbers: Vec<i64>, n_mixes: usize) -> Vec<i64> {
 }
 
 fn mix(numbers: &[i64], mix_index: &mut Vec<usize>) {
+    // a lone number has nowhere to move to
+    if numbers.len() < 2 {
+        return;
+    }
     for (idx, &number) in numbers.iter().enumerate() {
         let number_position = mix_index
             .iter()
@@ -43,6 +89,18 @@ fn mix(numbers: &[i64], mix_index: &mut Vec<usize>) {
     }
 }
 
+fn parse_numbers(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = parse::finish(input, parse(input))?;
+    if !numbers.contains(&0) {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a 0 to count the grove coordinates from",
+        ));
+    }
+    Ok(numbers)
+}
+
 fn parse(input: &str) -> IResult<&str, Vec<i64>> {
     separated_list1(newline, complete::i64)(input)
 }
@@ -50,6 +108,7 @@ fn parse(input: &str) -> IResult<&str, Vec<i64>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "1
 2
@@ -64,11 +123,13 @@ mod tests {
     #[test]
     fn it_works() {
         tracing_subscriber::fmt().with_max_level(Level::WARN).init();
+        let input = input::or_embedded(Day20::DAY, INPUT);
 
         let decryption_key = 811589153;
-        assert_eq!(solution(TEST_INPUT, 1, 1), 3);
-        println!("{}", solution(INPUT, 1, 1));
-        assert_eq!(solution(TEST_INPUT, decryption_key, 10), 1623178306);
-        println!("{}", solution(INPUT, decryption_key, 10));
+        assert_eq!(solution(TEST_INPUT, 1, 1), Ok(3));
+        println!("{}", solution(&input, 1, 1).unwrap());
+        assert_eq!(solution(TEST_INPUT, decryption_key, 10), Ok(1623178306));
+        println!("{}", solution(&input, decryption_key, 10).unwrap());
+        assert_eq!(solution("0\n", 1, 1), Ok(0));
     }
 }
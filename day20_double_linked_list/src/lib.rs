@@ -1,28 +1,61 @@
-#![feature(get_many_mut)]
+use std::fmt::Display;
+
+use itertools::Itertools;
 use nom::{
     character::complete::{self, newline},
     multi::separated_list1,
     IResult,
 };
+use render::{Frame, Recorder, Visualise};
+use solver::{parse, ParseError, Solver};
+use tracing::debug;
 
 #[derive(Debug, Clone, Copy)]
-struct Node {
+pub struct Node {
     number: i32,
-    idx: usize,
     is_head: bool,
     prev: usize,
     next: usize,
 }
 
-// when head moves, head.next becomes the head
-pub fn solution_a(input: &str) -> i32 {
-    let mut nodes = parse_nodes(input);
+pub struct Day20DoubleLinkedList;
+
+impl Solver for Day20DoubleLinkedList {
+    const DAY: u8 = 20;
+    type Parsed<'a> = Vec<Node>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_nodes(input)
+    }
+
+    fn part_a(nodes: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(nodes.clone(), None))
+    }
+}
+
+/// A frame of the whole list after each number moves, only legible for short inputs.
+impl Visualise for Day20DoubleLinkedList {
+    fn visualise_a(nodes: &Self::Parsed<'_>, _: &(), recorder: &mut Recorder) -> impl Display {
+        part_a(nodes.clone(), Some(recorder))
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<i32, ParseError> {
+    Ok(part_a(parse_nodes(input)?, None))
+}
 
-    // visualise(&nodes);
+// when head moves, head.next becomes the head
+fn part_a(mut nodes: Vec<Node>, mut recorder: Option<&mut Recorder>) -> i32 {
+    if let Some(recorder) = recorder.as_deref_mut() {
+        recorder.frame(|| draw(&nodes));
+    }
     for i in 0..nodes.len() {
         move_ith_node(&mut nodes, i);
         // println!("{nodes:?}");
-        // visualise(&nodes);
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.frame(|| draw(&nodes));
+        }
     }
 
     let start = nodes.iter().find(|n| n.number == 0).unwrap();
@@ -35,11 +68,12 @@ pub fn solution_a(input: &str) -> i32 {
             numbers.push(n.number);
         }
     }
-    println!("{numbers:?}");
+    debug!(?numbers);
     numbers.iter().sum()
 }
 
-fn visualise(nodes: &Vec<Node>) {
+/// The list from its head like the puzzle writes it, `1, 2, -3, ...`.
+fn draw(nodes: &[Node]) -> Frame {
     let head_position = nodes.iter().position(|n| n.is_head).unwrap();
     let head = nodes[head_position];
     let mut numbers = vec![head.number];
@@ -48,66 +82,63 @@ fn visualise(nodes: &Vec<Node>) {
         numbers.push(cur_node.number);
         cur_node = nodes[cur_node.next];
     }
-    println!("{numbers:?}");
+    let line = numbers.iter().join(", ");
+    let mut frame = Frame::new(line.len(), 1, ' ');
+    for (x, c) in line.chars().enumerate() {
+        frame[(x, 0)] = c;
+    }
+    frame
 }
 
 fn move_ith_node(nodes: &mut [Node], i: usize) {
     let curr = nodes[i];
     if curr.number == 0 {
-        // println!("{} does not move", current.number);
         return;
     }
 
-    let dest_idx = get_destination_idx(i, curr, nodes);
-    let dest = nodes[dest_idx];
-    unsafe {
-        let [curr_prev, curr, curr_next, dest, dest_next] =
-            nodes.get_many_unchecked_mut([curr.prev, i, curr.next, dest_idx, dest.next]);
-
-        curr_prev.next = curr_next.idx;
-        curr_next.prev = curr_prev.idx;
-        if curr.is_head {
-            curr_next.is_head = true;
-            curr.is_head = false;
-        }
-
-        dest.next = curr.idx;
-        curr.prev = dest.idx;
-        curr.next = dest_next.idx;
-        dest_next.prev = curr.idx;
+    // the other numbers are all that's left to step over once curr is unlinked
+    let steps = curr.number.rem_euclid(nodes.len() as i32 - 1);
+    if steps == 0 {
+        return;
+    }
+    nodes[curr.prev].next = curr.next;
+    nodes[curr.next].prev = curr.prev;
+    if curr.is_head {
+        nodes[curr.next].is_head = true;
+        nodes[i].is_head = false;
     }
-}
 
-fn get_destination_idx(i: usize, current: Node, nodes: &[Node]) -> usize {
-    let mut dest_idx = i;
-    let n_iter = if current.number.is_positive() {
-        current.number.abs()
-    } else {
-        current.number.abs() + 1
-    };
-    for _ in 0..n_iter {
-        dest_idx = match current.number {
-            n if n > 0 => nodes[dest_idx].next,
-            n if n < 0 => nodes[dest_idx].prev,
-            _ => unreachable!(),
-        };
+    let mut dest_idx = curr.prev;
+    for _ in 0..steps {
+        dest_idx = nodes[dest_idx].next;
     }
-    dest_idx
+    let dest_next = nodes[dest_idx].next;
+    nodes[dest_idx].next = i;
+    nodes[i].prev = dest_idx;
+    nodes[i].next = dest_next;
+    nodes[dest_next].prev = i;
 }
 
-fn parse_nodes(input: &str) -> Vec<Node> {
-    let (_, numbers) = parse(input).unwrap();
-    numbers
+fn parse_nodes(input: &str) -> Result<Vec<Node>, ParseError> {
+    let numbers = parse::finish(input, parse(input))?;
+    if !numbers.contains(&0) {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a 0 to count the grove coordinates from",
+        ));
+    }
+    let nodes = numbers
         .iter()
         .enumerate()
         .map(|(i, &number)| Node {
             number,
-            idx: i,
             is_head: i == 0,
             prev: if i == 0 { numbers.len() - 1 } else { i - 1 },
             next: if i == numbers.len() - 1 { 0 } else { i + 1 },
         })
-        .collect()
+        .collect();
+    Ok(nodes)
 }
 
 fn parse(input: &str) -> IResult<&str, Vec<i32>> {
@@ -117,6 +148,8 @@ fn parse(input: &str) -> IResult<&str, Vec<i32>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "1
 2
@@ -130,9 +163,26 @@ mod tests {
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 3);
-        println!("{}", solution_a(INPUT));
+        let input = input::or_embedded(Day20DoubleLinkedList::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(3));
+        println!("{}", solution_a(&input).unwrap());
         // assert_eq!(solution_b(TEST_INPUT), 58);
-        // println!("{}", solution_b(INPUT));
+        // println!("{}", solution_b(&input));
+    }
+
+    proptest! {
+        /// Lists with a single 0 like the puzzle's, the numbers wrap around short lists many times.
+        #[test]
+        fn agrees_with_day20(
+            (mut numbers, zero) in prop::collection::vec((-1000..1000i32).prop_filter("0", |n| *n != 0), 0..30)
+                .prop_flat_map(|numbers| {
+                    let len = numbers.len();
+                    (Just(numbers), 0..=len)
+                })
+        ) {
+            numbers.insert(zero, 0);
+            let input = numbers.iter().map(i32::to_string).collect::<Vec<_>>().join("\n");
+            prop_assert_eq!(solution_a(&input).map(i64::from), day20::solution(&input, 1, 1));
+        }
     }
 }
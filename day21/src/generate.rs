@@ -0,0 +1,123 @@
+//! Monkeys yelling numbers and doing jobs with them, `size` counts the monkeys. `humn` is a few
+//! jobs down one side of `root` and only additions, subtractions and small multiplications are on
+//! its way up, so the number it has to yell for part B is whole.
+
+use std::{collections::HashSet, mem};
+
+use generator::{
+    rand::{seq::SliceRandom, Rng},
+    Generate,
+};
+
+use crate::Day21;
+
+/// Largest number a job yells besides those on the way from `humn`, keeps part A within an i64.
+const LIMIT: i64 = 1_000_000_000;
+
+impl Generate for Day21 {
+    const SIZE: usize = 1819;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        // a monkey with a job waits on two others, so there's one more yelling a number than
+        // there are jobs, root and the one humn waits on are the least there can be
+        let jobs = (size.saturating_sub(1) / 2).max(2);
+        let depth = (jobs / 20).clamp(1, 100);
+        let mut subtrees = vec![0; depth + 1];
+        for _ in 0..jobs - 1 - depth {
+            subtrees[rng.gen_range(0..=depth)] += 1;
+        }
+
+        let mut monkeys = Monkeys::default();
+        let (other, mut number) = monkeys.subtree(subtrees[0], rng);
+        let mut waiting = monkeys.name(rng);
+        let operation = *['+', '-'].choose(rng).unwrap();
+        monkeys.wait("root", &waiting, operation, &other, rng);
+        // work down from root to humn, `number` is what the monkey waiting has to yell
+        let mut multiplied = 1;
+        for (level, &jobs) in subtrees[1..].iter().enumerate() {
+            let next = match level + 1 {
+                level if level == depth => "humn".to_string(),
+                _ => monkeys.name(rng),
+            };
+            let (sibling, value) = monkeys.subtree(jobs, rng);
+            let (operation, wanted) = match rng.gen_range(0..3) {
+                0 if value <= 10 && number % value == 0 && multiplied * value <= 1000 => {
+                    multiplied *= value;
+                    ('*', number / value)
+                }
+                1 => ('-', number + value),
+                _ => ('+', number - value),
+            };
+            monkeys.wait(&waiting, &next, operation, &sibling, rng);
+            waiting = next;
+            number = wanted;
+        }
+        monkeys.yell("humn", rng.gen_range(1..=4000), rng)
+    }
+}
+
+#[derive(Default)]
+struct Monkeys {
+    lines: Vec<String>,
+    names: HashSet<String>,
+}
+
+impl Monkeys {
+    fn name(&mut self, rng: &mut impl Rng) -> String {
+        loop {
+            let name: String = (0..4).map(|_| rng.gen_range('a'..='z')).collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// A monkey yelling the result of `jobs` jobs, and the number it yells.
+    fn subtree(&mut self, jobs: usize, rng: &mut impl Rng) -> (String, i64) {
+        let name = self.name(rng);
+        if jobs == 0 {
+            let number = rng.gen_range(1..=20);
+            self.lines.push(format!("{name}: {number}"));
+            return (name, number);
+        }
+        let left_jobs = rng.gen_range(0..jobs);
+        let mut left = self.subtree(left_jobs, rng);
+        let mut right = self.subtree(jobs - 1 - left_jobs, rng);
+        if left.1 < right.1 {
+            mem::swap(&mut left, &mut right);
+        }
+        // every number stays positive, so nothing is divided by 0, and there's always an
+        // operation that works as left is at least right
+        let (l, r) = (left.1, right.1);
+        let operations: Vec<(char, i64)> = [
+            (l + r <= LIMIT).then_some(('+', l + r)),
+            (l > r).then_some(('-', l - r)),
+            (l * r <= LIMIT).then_some(('*', l * r)),
+            (r > 1 && l % r == 0).then_some(('/', l / r)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let (operation, number) = *operations.choose(rng).unwrap();
+        self.lines
+            .push(format!("{name}: {} {operation} {}", left.0, right.0));
+        (name, number)
+    }
+
+    /// `name` doing `operation` on what `next` and `sibling` yell. `next` is on either side, but
+    /// first for a subtraction as that's the way round the numbers are worked out.
+    fn wait(&mut self, name: &str, next: &str, operation: char, sibling: &str, rng: &mut impl Rng) {
+        let line = if operation == '-' || rng.gen_bool(0.5) {
+            format!("{name}: {next} {operation} {sibling}")
+        } else {
+            format!("{name}: {sibling} {operation} {next}")
+        };
+        self.lines.push(line);
+    }
+
+    fn yell(mut self, name: &str, number: i64, rng: &mut impl Rng) -> String {
+        self.lines.push(format!("{name}: {number}"));
+        self.lines.shuffle(rng);
+        self.lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
@@ -1,19 +1,24 @@
-use hashbrown::HashMap;
+mod generate;
+
+use std::fmt::Display;
+
+use hashbrown::{HashMap, HashSet};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
-    character::complete::{self, anychar, newline, space1},
-    combinator::map,
+    character::complete::{self, newline, space1},
+    combinator::{map, value},
     multi::separated_list1,
     sequence::{delimited, separated_pair, tuple},
     IResult,
 };
+use solver::{parse, ParseError, Solver};
 
 use Monkey::*;
 use Operation::*;
 
 #[derive(Debug, Clone, Copy)]
-enum Operation {
+pub enum Operation {
     Add,
     Sub,
     Div,
@@ -30,39 +35,63 @@ impl Operation {
         }
     }
 
-    fn inverse_calculate(&self, result: i64, left: Option<i64>, right: Option<i64>) -> i64 {
-        // left = x Operation right
+    fn checked_calculate(&self, left: i64, right: i64) -> Option<i64> {
         match self {
-            Add => result - left.or(right).unwrap(),
-            Sub => match (left, right) {
-                // result = x - right
-                (None, Some(right)) => result + right,
-                // result = left - x
-                (Some(left), None) => left - result,
-                _ => unreachable!(),
-            },
-            Div => match (left, right) {
-                // result = x / right
-                (None, Some(right)) => result * right,
-                // result = right / x
-                (Some(right), None) => right / result,
-                _ => unreachable!(),
-            },
-            Mul => result / left.or(right).unwrap(),
+            Add => left.checked_add(right),
+            Sub => left.checked_sub(right),
+            Div => left.checked_div(right),
+            Mul => left.checked_mul(right),
+        }
+    }
+
+    /// What `x` has to be for `left x right` to give `result`, with `x` on the side that's
+    /// `None`. `None` if it overflows or divides by zero.
+    fn inverse_calculate(&self, result: i64, left: Option<i64>, right: Option<i64>) -> Option<i64> {
+        match (self, left, right) {
+            (Add, Some(known), None) | (Add, None, Some(known)) => result.checked_sub(known),
+            // result = x - right
+            (Sub, None, Some(right)) => result.checked_add(right),
+            // result = left - x
+            (Sub, Some(left), None) => left.checked_sub(result),
+            // result = x / right
+            (Div, None, Some(right)) => result.checked_mul(right),
+            // result = left / x
+            (Div, Some(left), None) => left.checked_div(result),
+            (Mul, Some(known), None) | (Mul, None, Some(known)) => result.checked_div(known),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug)]
-enum Monkey<'a> {
+pub enum Monkey<'a> {
     Job(Operation, &'a str, &'a str),
     Number(i64),
 }
 
-pub fn solution_a(input: &str) -> i64 {
-    let (_, monkeys) = parse(input).unwrap();
-    let monkeys = HashMap::<&str, Monkey>::from_iter(monkeys);
-    calculate(&monkeys, "root")
+pub struct Day21;
+
+impl Solver for Day21 {
+    const DAY: u8 = 21;
+    type Parsed<'a> = HashMap<&'a str, Monkey<'a>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_a(monkeys: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(calculate(monkeys, "root"))
+    }
+
+    fn part_b(monkeys: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        part_b(monkeys)
+    }
+}
+
+pub fn solution_a(input: &str) -> Result<i64, ParseError> {
+    let monkeys = parse_monkeys(input)?;
+    Ok(calculate(&monkeys, "root"))
 }
 
 fn calculate(monkeys: &HashMap<&str, Monkey>, monkey: &str) -> i64 {
@@ -100,56 +129,128 @@ fn calculate_rec(monkeys: &HashMap<&str, Monkey>, monkey: &str) -> MonkeyRec {
     }
 }
 
-pub fn solution_b(input: &str) -> i64 {
-    let (_, monkeys) = parse(input).unwrap();
-    let monkeys = HashMap::<&str, Monkey>::from_iter(monkeys);
+/// `None` when there's no single number for `humn` to yell: without `humn`, with `root` not
+/// waiting on it from exactly one side, with a job waiting on it from both sides, or when
+/// working back to it overflows or divides by zero.
+pub fn solution_b(input: &str) -> Result<Option<i64>, ParseError> {
+    let monkeys = parse_monkeys(input)?;
+    Ok(part_b(&monkeys))
+}
 
-    match &monkeys["root"] {
-        Job(_, left, right) => {
-            let left = calculate_rec(&monkeys, left);
-            let right = calculate_rec(&monkeys, right);
-            match (&left, &right) {
-                (MonkeyRec::Number(lhs), MonkeyRec::Job(..)) => solve_equality(*lhs, right),
-                (MonkeyRec::Job(..), MonkeyRec::Number(lhs)) => solve_equality(*lhs, left),
-                _ => unreachable!(),
-            }
+fn part_b(monkeys: &HashMap<&str, Monkey>) -> Option<i64> {
+    if !monkeys.contains_key("humn") {
+        return None;
+    }
+    let Job(_, left, right) = &monkeys["root"] else {
+        return None;
+    };
+    match (calculate_rec(monkeys, left), calculate_rec(monkeys, right)) {
+        (MonkeyRec::Number(_), MonkeyRec::Number(_)) => None,
+        (MonkeyRec::Number(result), rhs) | (rhs, MonkeyRec::Number(result)) => {
+            solve_equality(result, rhs)
         }
-        _ => unreachable!(),
+        _ => None,
     }
 }
 
-fn solve_equality(result: i64, rhs: MonkeyRec) -> i64 {
+/// What `humn` has to yell for `rhs` to give `result`.
+fn solve_equality(result: i64, rhs: MonkeyRec) -> Option<i64> {
     match rhs {
-        MonkeyRec::Job(operation, left, right) => {
-            let left = *left;
-            let right = *right;
-            match (&left, &right) {
-                (MonkeyRec::Number(left), MonkeyRec::Job(..)) => solve_equality(
-                    operation.inverse_calculate(result, Some(*left), None),
-                    right,
-                ),
-                (MonkeyRec::Job(..), MonkeyRec::Number(right)) => solve_equality(
-                    operation.inverse_calculate(result, None, Some(*right)),
-                    left,
-                ),
-                (MonkeyRec::Number(left), MonkeyRec::Human) => {
-                    operation.inverse_calculate(result, Some(*left), None)
-                }
-                (MonkeyRec::Human, MonkeyRec::Number(right)) => {
-                    operation.inverse_calculate(result, None, Some(*right))
-                }
-                _ => unreachable!(),
+        MonkeyRec::Human => Some(result),
+        MonkeyRec::Number(_) => None,
+        MonkeyRec::Job(operation, left, right) => match (*left, *right) {
+            (MonkeyRec::Number(left), right) => solve_equality(
+                operation.inverse_calculate(result, Some(left), None)?,
+                right,
+            ),
+            (left, MonkeyRec::Number(right)) => solve_equality(
+                operation.inverse_calculate(result, None, Some(right))?,
+                left,
+            ),
+            // humn on both sides
+            _ => None,
+        },
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<HashMap<&str, Monkey<'_>>, ParseError> {
+    let monkeys = parse::finish(input, parse(input))?;
+    let names: HashSet<&str> = monkeys.iter().map(|(name, _)| *name).collect();
+    for (line, (_, monkey)) in input.lines().zip(&monkeys) {
+        if let Job(_, left, right) = monkey {
+            if let Some(unknown) = [left, right]
+                .into_iter()
+                .find(|name| !names.contains(*name))
+            {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("job waits on unknown monkey {unknown:?}"),
+                ));
             }
         }
-        _ => unreachable!(),
     }
+    if !names.contains("root") {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a monkey named root",
+        ));
+    }
+    let lines: HashMap<&str, &str> = monkeys
+        .iter()
+        .zip(input.lines())
+        .map(|((name, _), line)| (*name, line))
+        .collect();
+    let order: Vec<&str> = monkeys.iter().map(|(name, _)| *name).collect();
+    let monkeys = HashMap::from_iter(monkeys);
+    let mut checked = HashMap::new();
+    for name in order {
+        check_job(input, &lines, &monkeys, name, &mut checked)?;
+    }
+    Ok(monkeys)
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<(&str, Monkey)>> {
+/// Works out every job like part A will to reject those it can't do: waiting on their own result,
+/// dividing by zero or overflowing. Part B works out the same jobs besides those waiting on
+/// `humn`. `None` in `checked` marks the monkeys still being worked out.
+fn check_job<'a>(
+    input: &str,
+    lines: &HashMap<&str, &str>,
+    monkeys: &HashMap<&'a str, Monkey<'a>>,
+    monkey: &'a str,
+    checked: &mut HashMap<&'a str, Option<i64>>,
+) -> Result<i64, ParseError> {
+    let line = lines[monkey];
+    match checked.get(monkey) {
+        Some(Some(result)) => return Ok(*result),
+        Some(None) => return Err(ParseError::new(input, line, "job waits on its own result")),
+        None => {}
+    }
+    checked.insert(monkey, None);
+    let result = match &monkeys[monkey] {
+        Number(number) => *number,
+        Job(operation, left, right) => {
+            let left = check_job(input, lines, monkeys, left, checked)?;
+            let right = check_job(input, lines, monkeys, right, checked)?;
+            operation.checked_calculate(left, right).ok_or_else(|| {
+                let message = match operation {
+                    Div if right == 0 => "job divides by zero",
+                    _ => "job overflows an i64",
+                };
+                ParseError::new(input, line, message)
+            })?
+        }
+    };
+    checked.insert(monkey, Some(result));
+    Ok(result)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<(&str, Monkey<'_>)>> {
     separated_list1(newline, parse_line)(input)
 }
 
-fn parse_line(input: &str) -> IResult<&str, (&str, Monkey)> {
+fn parse_line(input: &str) -> IResult<&str, (&str, Monkey<'_>)> {
     separated_pair(
         take(4usize),
         tag(": "),
@@ -157,29 +258,30 @@ fn parse_line(input: &str) -> IResult<&str, (&str, Monkey)> {
     )(input)
 }
 
-fn parse_job(input: &str) -> IResult<&str, Monkey> {
+fn parse_job(input: &str) -> IResult<&str, Monkey<'_>> {
     map(
         tuple((
             take(4usize),
-            delimited(space1, anychar, space1),
+            delimited(
+                space1,
+                alt((
+                    value(Add, tag("+")),
+                    value(Sub, tag("-")),
+                    value(Div, tag("/")),
+                    value(Mul, tag("*")),
+                )),
+                space1,
+            ),
             take(4usize),
         )),
-        |(left, operation, right)| {
-            let operation = match operation {
-                '+' => Add,
-                '-' => Sub,
-                '/' => Div,
-                '*' => Mul,
-                _ => unreachable!(),
-            };
-            Job(operation, left, right)
-        },
+        |(left, operation, right)| Job(operation, left, right),
     )(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     const TEST_INPUT: &str = "root: pppw + sjmn
 dbpl: 5
@@ -201,9 +303,32 @@ hmdt: 32";
 
     #[test]
     fn it_works() {
-        assert_eq!(solution_a(TEST_INPUT), 152);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT), 301);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day21::DAY, INPUT);
+        assert_eq!(solution_a(TEST_INPUT), Ok(152));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT), Ok(Some(301)));
+        println!("{}", solution_b(&input).unwrap().unwrap());
+
+        let cyclic = "root: aaaa + humn\naaaa: bbbb + cccc\nbbbb: aaaa + cccc\ncccc: 1\nhumn: 2";
+        let err = solution_a(cyclic).unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (2, "job waits on its own result")
+        );
+        let err = solution_a("root: aaaa + humn\naaaa: bbbb / cccc\nbbbb: 1\ncccc: 0\nhumn: 2");
+        assert_eq!(err.unwrap_err().message, "job divides by zero");
+        // part B's limits don't keep part A from its answer
+        assert_eq!(solution_a("root: aaaa + bbbb\naaaa: 1\nbbbb: 2"), Ok(3));
+        assert_eq!(solution_b("root: aaaa + bbbb\naaaa: 1\nbbbb: 2"), Ok(None));
+        assert_eq!(solution_a("root: 7"), Ok(7));
+        assert_eq!(solution_b("root: 7\nhumn: 1"), Ok(None));
+        let both = "root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 4\nhumn: 2";
+        assert_eq!((solution_a(both), solution_b(both)), (Ok(8), Ok(None)));
+        assert_eq!(
+            solution_b("root: humn + bbbb\nbbbb: 4\nhumn: 2"),
+            Ok(Some(4))
+        );
+        let zero = "root: aaaa + bbbb\naaaa: humn * cccc\nbbbb: 4\ncccc: 0\nhumn: 2";
+        assert_eq!((solution_a(zero), solution_b(zero)), (Ok(4), Ok(None)));
     }
 }
@@ -0,0 +1,68 @@
+//! A map and the path to walk on it, `size` counts the turns in the path. The map is a cube
+//! unfolded along a random set of its edges, so any of the eleven nets in any orientation, with
+//! faces 4 to 50 tiles wide.
+
+use generator::{rand::Rng, Generate};
+
+use crate::{Coord, Day22, Face, Vec3};
+
+impl Generate for Day22 {
+    const SIZE: usize = 2000;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        // unfold face by face from the top, in faces across and down; six faces can't reach
+        // further than five faces from the first
+        let mut faces = vec![Face {
+            corner: Coord::new(5, 5),
+            right: Vec3::new(1, 0, 0),
+            down: Vec3::new(0, 1, 0),
+            out: Vec3::new(0, 0, 1),
+        }];
+        while faces.len() < 6 {
+            let face = faces[rng.gen_range(0..faces.len())];
+            let dir = geometry::Dir4::ALL[rng.gen_range(0..4)];
+            let next = face.fold(dir, face.corner.step(dir));
+            if faces.iter().all(|other| other.out != next.out) {
+                faces.push(next);
+            }
+        }
+        let left = faces.iter().map(|face| face.corner.x).min().unwrap();
+        let top = faces.iter().map(|face| face.corner.y).min().unwrap();
+        let net: Vec<_> = faces
+            .iter()
+            .map(|face| (face.corner.x - left, face.corner.y - top))
+            .collect();
+        let start = net.iter().filter(|(_, y)| *y == 0).map(|(x, _)| *x).min();
+
+        let side = rng.gen_range(4..=50);
+        let mut map = String::new();
+        for (face_y, y) in (0..=net.iter().map(|(_, y)| *y).max().unwrap())
+            .flat_map(|face_y| (0..side).map(move |y| (face_y, y)))
+        {
+            // only the blank before a face is drawn, like in the real input
+            let width = net.iter().filter(|(_, y)| *y == face_y).map(|(x, _)| x + 1);
+            for face_x in 0..width.max().unwrap() {
+                for x in 0..side {
+                    if !net.contains(&(face_x, face_y)) {
+                        map.push(' ');
+                    } else if (face_y, y, x) == (0, 0, 0) && Some(face_x) == start
+                        || !rng.gen_bool(0.1)
+                    {
+                        // the path starts on the leftmost tile of the top row, it can't be a wall
+                        map.push('.');
+                    } else {
+                        map.push('#');
+                    }
+                }
+            }
+            map.push('\n');
+        }
+
+        let mut path = rng.gen_range(1..=50).to_string();
+        for _ in 0..size {
+            let turn = if rng.gen_bool(0.5) { 'L' } else { 'R' };
+            path.push_str(&format!("{turn}{}", rng.gen_range(1..=50)));
+        }
+        format!("{map}\n{path}\n")
+    }
+}
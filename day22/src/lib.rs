@@ -1,293 +1,371 @@
-#[macro_use]
-extern crate lazy_static;
+mod generate;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
-use nom::{branch::alt, character::complete, combinator::map, multi::many1, IResult};
+use geometry::{Dir4, Point, Point3, Turn};
+use grid::Grid;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::{map, value},
+    multi::many1,
+    IResult,
+};
+use render::{Frame, Recorder, Visualise};
+use solver::{parse, ParseError, Solver};
 
-type FoldType = Vec<((&'static str, Dir), (&'static str, Dir), bool)>;
+pub type Coord = Point<usize>;
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub struct Coord {
-    x: u32,
-    y: u32,
+type Vec3 = Point3<i32>;
+
+/// Where stepping off an edge of a face leads and which way it faces there, by the tile on the
+/// edge and the way off it.
+type Wraps = HashMap<(Coord, Dir4), (Coord, Dir4)>;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Turn(Turn),
+    Distance(u32),
+}
+
+/// How the edges of the map join up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wrap {
+    /// Round to the other end of the row or column.
+    Flat,
+    /// Over to the next face of the cube the map folds into.
+    Cube,
 }
 
+/// The map with both ways its edges join up.
+pub struct Map {
+    grid: Grid<char>,
+    flat: Wraps,
+    cube: Wraps,
+}
+
+/// A face of the net: its top left tile, and which ways its right, down and outside point once
+/// folded into a cube.
 #[derive(Debug, Clone, Copy)]
-struct Node {
-    coord: Coord,
-    is_wall: bool,
-    up: Coord,
-    down: Coord,
-    left: Coord,
-    right: Coord,
+struct Face {
+    corner: Coord,
+    right: Vec3,
+    down: Vec3,
+    out: Vec3,
 }
 
-impl Node {
-    fn get_neighbour(&self, direction: &Dir) -> Coord {
-        match direction {
-            Dir::U => self.up,
-            Dir::D => self.down,
-            Dir::L => self.left,
-            Dir::R => self.right,
+impl Face {
+    /// The way over the edge in `dir`, once folded.
+    fn towards(&self, dir: Dir4) -> Vec3 {
+        match dir {
+            Dir4::Right => self.right,
+            Dir4::Down => self.down,
+            Dir4::Left => -self.right,
+            Dir4::Up => -self.down,
         }
     }
-}
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub enum Dir {
-    U,
-    D,
-    L,
-    R,
-}
+    /// The way the tiles on the edge in `dir` count up, once folded.
+    fn along(&self, dir: Dir4) -> Vec3 {
+        match dir {
+            Dir4::Left | Dir4::Right => self.down,
+            Dir4::Up | Dir4::Down => self.right,
+        }
+    }
 
-impl Dir {
-    fn opposite(&self) -> Self {
-        match self {
-            Dir::U => Dir::D,
-            Dir::D => Dir::U,
-            Dir::L => Dir::R,
-            Dir::R => Dir::L,
+    /// The face next to this one over the edge in `dir`, folded down to meet it.
+    fn fold(&self, dir: Dir4, corner: Coord) -> Face {
+        let (right, down, out) = match dir {
+            Dir4::Right => (-self.out, self.down, self.right),
+            Dir4::Left => (self.out, self.down, -self.right),
+            Dir4::Down => (self.right, -self.out, self.down),
+            Dir4::Up => (self.right, self.out, -self.down),
+        };
+        Face {
+            corner,
+            right,
+            down,
+            out,
         }
     }
+
+    /// The `i`th tile on the edge in `dir`.
+    fn edge(&self, dir: Dir4, i: usize, side: usize) -> Coord {
+        let (x, y) = match dir {
+            Dir4::Right => (side - 1, i),
+            Dir4::Left => (0, i),
+            Dir4::Down => (i, side - 1),
+            Dir4::Up => (i, 0),
+        };
+        Coord::new(self.corner.x + x, self.corner.y + y)
+    }
 }
 
-#[derive(Debug)]
-enum Rotation {
-    R,
-    L,
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY: u8 = 22;
+    type Parsed<'a> = (Map, Vec<Instruction>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a((map, instructions): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(walk(map, instructions, Wrap::Flat, None))
+    }
+
+    fn part_b((map, instructions): &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(walk(map, instructions, Wrap::Cube, None))
+    }
 }
 
-impl Rotation {
-    fn rotate(&self, dir: Dir) -> Dir {
-        match (dir, self) {
-            (Dir::U, Rotation::R) => Dir::R,
-            (Dir::U, Rotation::L) => Dir::L,
-            (Dir::D, Rotation::R) => Dir::L,
-            (Dir::D, Rotation::L) => Dir::R,
-            (Dir::L, Rotation::R) => Dir::U,
-            (Dir::L, Rotation::L) => Dir::D,
-            (Dir::R, Rotation::R) => Dir::D,
-            (Dir::R, Rotation::L) => Dir::U,
-        }
+/// A frame each step along the path.
+impl Visualise for Day22 {
+    fn visualise_a(
+        (map, instructions): &Self::Parsed<'_>,
+        _: &(),
+        recorder: &mut Recorder,
+    ) -> impl Display {
+        walk(map, instructions, Wrap::Flat, Some(recorder))
+    }
+
+    fn visualise_b(
+        (map, instructions): &Self::Parsed<'_>,
+        _: &(),
+        recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        Some(walk(map, instructions, Wrap::Cube, Some(recorder)))
     }
 }
 
-#[derive(Debug)]
-enum Instruction {
-    Rotate(Rotation),
-    Distance(u32),
+pub fn solution(input: &str, wrap: Wrap) -> Result<usize, ParseError> {
+    let (map, instructions) = parse(input)?;
+    Ok(walk(&map, &instructions, wrap, None))
 }
 
-pub fn solution(input: &str, edges: HashMap<&str, (Coord, Coord)>, fold: FoldType) -> u32 {
-    let (graph_str, inst_str) = input.split_once("\n\n").unwrap();
-    let edge_map = get_edge_map(edges, fold);
-    let grid = parse_grid(graph_str);
-    let start_coord = Coord {
-        x: grid[0].iter().position(|c| c == &'.').unwrap() as u32 + 1,
-        y: 1,
+fn walk(
+    map: &Map,
+    instructions: &[Instruction],
+    wrap: Wrap,
+    mut recorder: Option<&mut Recorder>,
+) -> usize {
+    let wraps = match wrap {
+        Wrap::Flat => &map.flat,
+        Wrap::Cube => &map.cube,
     };
-    let graph = parse_graph(&edge_map, &grid);
-    let (_, instructions) = parse_instructions(inst_str).unwrap();
-    let mut cur_node = &graph[&start_coord];
-    let mut cur_dir = Dir::R;
+    let start = Coord::new(map.grid.row(0).iter().position(|c| c == &'.').unwrap(), 0);
+    let (mut coord, mut dir) = (start, Dir4::Right);
     let mut path = vec![];
     for inst in instructions {
         match inst {
-            Instruction::Rotate(rot) => cur_dir = rot.rotate(cur_dir),
+            Instruction::Turn(turn) => dir = dir.turn(*turn),
             Instruction::Distance(dist) => {
-                for _ in 0..dist {
-                    path.push((cur_dir, *cur_node));
-                    // visualise(&start_coord, &path, &grid);
-                    let neighbour = &graph[&cur_node.get_neighbour(&cur_dir)];
-                    if neighbour.is_wall {
-                        break;
+                for _ in 0..*dist {
+                    if let Some(recorder) = recorder.as_deref_mut() {
+                        path.push((dir, coord));
+                        recorder.frame(|| draw(&start, &path, &map.grid));
                     }
-                    if let Some((_, new_dir)) = &edge_map.get(&(cur_node.coord, cur_dir)) {
-                        cur_dir = *new_dir;
+                    let (next, next_dir) = wraps
+                        .get(&(coord, dir))
+                        .copied()
+                        .unwrap_or_else(|| (coord.step(dir), dir));
+                    if map.grid[(next.x, next.y)] == '#' {
+                        break;
                     }
-                    cur_node = neighbour;
+                    (coord, dir) = (next, next_dir);
                 }
             }
         }
     }
-    cur_node.coord.y * 1000
-        + cur_node.coord.x * 4
-        + match cur_dir {
-            Dir::U => 3,
-            Dir::D => 1,
-            Dir::L => 2,
-            Dir::R => 0,
+    (coord.y + 1) * 1000
+        + (coord.x + 1) * 4
+        + match dir {
+            Dir4::Up => 3,
+            Dir4::Down => 1,
+            Dir4::Left => 2,
+            Dir4::Right => 0,
         }
 }
 
-fn get_edge_pairs(
-    from: (Coord, Coord),
-    to: (Coord, Coord),
-    is_same_direction: bool,
-) -> Vec<(Coord, Coord)> {
-    let is_from_horizontal = from.0.y == from.1.y;
-    let is_to_horizontal = to.0.y == to.1.y;
-    let from_coords = get_edge_coords(is_from_horizontal, from);
-    let to_coords = get_edge_coords(is_to_horizontal, to);
-    if is_same_direction {
-        from_coords.into_iter().zip(to_coords.into_iter()).collect()
-    } else {
-        from_coords
-            .into_iter()
-            .zip(to_coords.into_iter().rev())
-            .collect()
-    }
+/// The tile next to `coord` in `dir`, `None` off the map.
+fn next_tile(grid: &Grid<char>, coord: Coord, dir: Dir4) -> Option<Coord> {
+    let next = match dir {
+        Dir4::Up => Coord::new(coord.x, coord.y.checked_sub(1)?),
+        Dir4::Left => Coord::new(coord.x.checked_sub(1)?, coord.y),
+        Dir4::Right | Dir4::Down => coord.step(dir),
+    };
+    grid.get((next.x, next.y))
+        .is_some_and(|c| c != &' ')
+        .then_some(next)
 }
 
-fn get_edge_coords(is_edge_horizontal: bool, edge: (Coord, Coord)) -> Vec<Coord> {
-    let mut edge_coords = vec![];
-    if is_edge_horizontal {
-        let y = edge.0.y;
-        for x in edge.0.x..=edge.1.x {
-            edge_coords.push(Coord { x, y });
-        }
-    } else {
-        let x = edge.0.x;
-        for y in edge.0.y..=edge.1.y {
-            edge_coords.push(Coord { x, y });
+/// Stepping off the map comes back on at the other end of the row or column.
+fn flat_wraps(grid: &Grid<char>) -> Wraps {
+    let mut wraps = Wraps::new();
+    for ((x, y), _) in grid.iter().filter(|(_, c)| c != &&' ') {
+        let coord = Coord::new(x, y);
+        for dir in Dir4::ALL {
+            if next_tile(grid, coord, dir).is_some() {
+                continue;
+            }
+            let mut other = coord;
+            while let Some(next) = next_tile(grid, other, dir.opposite()) {
+                other = next;
+            }
+            wraps.insert((coord, dir), (other, dir));
         }
     }
-    edge_coords
+    wraps
 }
 
-fn get_edge_map(
-    edges: HashMap<&str, (Coord, Coord)>,
-    fold: FoldType,
-) -> HashMap<(Coord, Dir), (Coord, Dir)> {
-    let mut map = HashMap::new();
-    for ((from_edge, from_dir), (to_edge, to_dir), is_same_direction) in fold {
-        let edge_pairs = get_edge_pairs(edges[&from_edge], edges[&to_edge], is_same_direction);
-        for (from, to) in edge_pairs {
-            map.insert((from, from_dir), (to, to_dir));
-            map.insert((to, to_dir.opposite()), (from, from_dir.opposite()));
-        }
+/// Folds the map into a cube by walking its net from the top left face, `None` if the map isn't
+/// six square faces that fold into one.
+fn cube_wraps(grid: &Grid<char>) -> Option<Wraps> {
+    let tiles = grid.iter().filter(|(_, c)| c != &&' ').count();
+    let side = (1..).find(|side| 6 * side * side >= tiles)?;
+    if tiles == 0 || 6 * side * side != tiles {
+        return None;
     }
-    map
-}
-
-fn visualise(start_coord: &Coord, path: &Vec<(Dir, Node)>, grid: &Vec<Vec<char>>) {
-    let mut grid = grid.clone();
-    for (dir, n) in path {
-        match dir {
-            Dir::U => grid[n.coord.y as usize - 1][n.coord.x as usize - 1] = '^',
-            Dir::D => grid[n.coord.y as usize - 1][n.coord.x as usize - 1] = 'v',
-            Dir::L => grid[n.coord.y as usize - 1][n.coord.x as usize - 1] = '<',
-            Dir::R => grid[n.coord.y as usize - 1][n.coord.x as usize - 1] = '>',
+    let first = Face {
+        corner: Coord::new(grid.row(0).iter().position(|c| c != &' ')?, 0),
+        right: Vec3::new(1, 0, 0),
+        down: Vec3::new(0, 1, 0),
+        out: Vec3::new(0, 0, 1),
+    };
+    let mut faces = vec![first];
+    let mut unfolded = vec![first];
+    while let Some(face) = unfolded.pop() {
+        for dir in Dir4::ALL {
+            let corner = match dir {
+                Dir4::Up => face
+                    .corner
+                    .y
+                    .checked_sub(side)
+                    .map(|y| Coord::new(face.corner.x, y)),
+                Dir4::Left => face
+                    .corner
+                    .x
+                    .checked_sub(side)
+                    .map(|x| Coord::new(x, face.corner.y)),
+                Dir4::Right => Some(Coord::new(face.corner.x + side, face.corner.y)),
+                Dir4::Down => Some(Coord::new(face.corner.x, face.corner.y + side)),
+            };
+            let Some(corner) = corner else { continue };
+            if grid.get((corner.x, corner.y)).is_some_and(|c| c != &' ')
+                && faces.iter().all(|other| other.corner != corner)
+            {
+                faces.push(face.fold(dir, corner));
+                unfolded.push(face.fold(dir, corner));
+            }
         }
     }
-    grid[start_coord.y as usize - 1][start_coord.x as usize - 1] = '>';
-
-    let grid_str: Vec<String> = grid
-        .iter()
-        .map(|line| line.iter().collect::<String>())
-        .collect();
-    println!("{}", grid_str.join("\n"));
-    println!();
-}
+    let full = |face: &Face| {
+        (0..side).all(|y| {
+            (0..side).all(|x| {
+                grid.get((face.corner.x + x, face.corner.y + y))
+                    .is_some_and(|c| c != &' ')
+            })
+        })
+    };
+    let overlap = |face: &Face| faces.iter().filter(|other| other.out == face.out).count() > 1;
+    if faces.len() != 6 || !faces.iter().all(full) || faces.iter().any(overlap) {
+        return None;
+    }
 
-fn parse_graph(
-    edge_map: &HashMap<(Coord, Dir), (Coord, Dir)>,
-    grid: &Vec<Vec<char>>,
-) -> HashMap<Coord, Node> {
-    let mut graph = HashMap::new();
-    for y in 0..grid.len() {
-        for x in 0..grid[0].len() {
-            let char = grid[y][x];
-            if char == ' ' {
-                continue;
+    let mut wraps = Wraps::new();
+    for face in &faces {
+        for dir in Dir4::ALL {
+            let other = faces.iter().find(|other| other.out == face.towards(dir))?;
+            let other_dir = Dir4::ALL
+                .into_iter()
+                .find(|other_dir| other.towards(*other_dir) == face.out)?;
+            let reversed = other.along(other_dir) != face.along(dir);
+            for i in 0..side {
+                let j = if reversed { side - 1 - i } else { i };
+                wraps.insert(
+                    (face.edge(dir, i, side), dir),
+                    (other.edge(other_dir, j, side), other_dir.opposite()),
+                );
             }
-            let coord = Coord {
-                x: x as u32 + 1,
-                y: y as u32 + 1,
-            };
-            let node = Node {
-                coord,
-                is_wall: char == '#',
-                up: get_neighbour(edge_map, &coord, &Dir::U),
-                down: get_neighbour(edge_map, &coord, &Dir::D),
-                left: get_neighbour(edge_map, &coord, &Dir::L),
-                right: get_neighbour(edge_map, &coord, &Dir::R),
-            };
-            graph.insert(coord, node);
         }
     }
-    graph
+    Some(wraps)
 }
 
-fn parse_grid(input: &str) -> Vec<Vec<char>> {
-    let x_max = input.lines().map(|line| line.len()).max().unwrap();
-    let y_max = input.lines().count();
-    let mut grid = vec![vec![' '; x_max]; y_max];
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            grid[y][x] = c;
-        }
+/// The map like the puzzle draws it, with the path so far.
+fn draw(start: &Coord, path: &[(Dir4, Coord)], grid: &Grid<char>) -> Frame {
+    let mut grid = grid.clone();
+    for (dir, coord) in path {
+        grid[(coord.x, coord.y)] = match dir {
+            Dir4::Up => '^',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+            Dir4::Right => '>',
+        };
     }
+    grid[(start.x, start.y)] = '>';
     grid
 }
 
-fn get_neighbour(
-    map: &HashMap<(Coord, Dir), (Coord, Dir)>,
-    coord: &Coord,
-    direction: &Dir,
-) -> Coord {
-    if let Some((destination, _)) = map.get(&(*coord, *direction)) {
-        return *destination;
-    }
-    match direction {
-        Dir::U => Coord {
-            x: coord.x,
-            y: coord.y - 1,
-        },
-        Dir::D => Coord {
-            x: coord.x,
-            y: coord.y + 1,
-        },
-        Dir::L => Coord {
-            x: coord.x - 1,
-            y: coord.y,
-        },
-        Dir::R => Coord {
-            x: coord.x + 1,
-            y: coord.y,
-        },
+fn parse(input: &str) -> Result<(Map, Vec<Instruction>), ParseError> {
+    let Some((graph_str, inst_str)) = input.split_once("\n\n") else {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "expected a blank line between the map and the path",
+        ));
+    };
+    // rows are padded with spaces to the widest one, like the blank parts of the net
+    let grid = Grid::parse_padded(graph_str, ' ', |c| {
+        matches!(c, ' ' | '.' | '#').then_some(c)
+    })?;
+    let first_row = graph_str.lines().next().unwrap_or(graph_str);
+    if !first_row.contains('.') {
+        return Err(ParseError::new(
+            input,
+            first_row,
+            "expected an open tile to start on",
+        ));
     }
+    let Some(cube) = cube_wraps(&grid) else {
+        return Err(ParseError::new(
+            input,
+            graph_str,
+            "expected a map of six square faces that fold into a cube",
+        ));
+    };
+    let instructions = parse::finish(input, parse_instructions(inst_str))?;
+    let flat = flat_wraps(&grid);
+    Ok((Map { grid, flat, cube }, instructions))
 }
 
 fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
     many1(alt((
         map(complete::u32, Instruction::Distance),
-        map(complete::alpha1, |c| match c {
-            "L" => Instruction::Rotate(Rotation::L),
-            "R" => Instruction::Rotate(Rotation::R),
-            _ => unreachable!(),
-        }),
+        value(Instruction::Turn(Turn::Left), tag("L")),
+        value(Instruction::Turn(Turn::Right), tag("R")),
     )))(input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     #[test]
     fn it_works() {
-        assert_eq!(
-            solution(TEST_INPUT, TEST_EDGES.to_owned(), TEST_FOLD_A.to_vec()),
-            6032
-        );
-        println!("{}", solution(INPUT, EDGES.to_owned(), FOLD_A.to_vec()));
-        assert_eq!(
-            solution(TEST_INPUT, TEST_EDGES.to_owned(), TEST_FOLD_B.to_vec()),
-            5031
-        );
-        println!("{}", solution(INPUT, EDGES.to_owned(), FOLD_B.to_vec()));
+        let input = input::or_embedded(Day22::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT, Wrap::Flat), Ok(6032));
+        println!("{}", solution(&input, Wrap::Flat).unwrap());
+        assert_eq!(solution(TEST_INPUT, Wrap::Cube), Ok(5031));
+        println!("{}", solution(&input, Wrap::Cube).unwrap());
+
+        // six faces in a row wrap round onto each other
+        let strip = "......\n\n1";
+        assert!(solution(strip, Wrap::Flat).is_err());
     }
 
     const TEST_INPUT: &str = "        ...#
@@ -306,87 +384,4 @@ mod tests {
 10R5L5R10L4R5L5";
 
     const INPUT: &str = include_str!("input.txt");
-
-    lazy_static! {
-        static ref TEST_EDGES: HashMap<&'static str, (Coord, Coord)> = {
-            HashMap::from([
-                ("1a", (Coord { x: 5, y: 5 }, Coord { x: 8, y: 5 })),
-                ("1b", (Coord { x: 9, y: 1 }, Coord { x: 9, y: 4 })),
-                ("2a", (Coord { x: 5, y: 8 }, Coord { x: 8, y: 8 })),
-                ("2b", (Coord { x: 9, y: 9 }, Coord { x: 9, y: 12 })),
-                ("3a", (Coord { x: 1, y: 8 }, Coord { x: 4, y: 8 })),
-                ("3b", (Coord { x: 9, y: 12 }, Coord { x: 12, y: 12 })),
-                ("4a", (Coord { x: 1, y: 5 }, Coord { x: 4, y: 5 })),
-                ("4b", (Coord { x: 9, y: 1 }, Coord { x: 12, y: 1 })),
-                ("5a", (Coord { x: 1, y: 5 }, Coord { x: 1, y: 8 })),
-                ("5b", (Coord { x: 13, y: 12 }, Coord { x: 16, y: 12 })),
-                ("6a", (Coord { x: 12, y: 5 }, Coord { x: 12, y: 8 })),
-                ("6b", (Coord { x: 13, y: 9 }, Coord { x: 16, y: 9 })),
-                ("7a", (Coord { x: 13, y: 1 }, Coord { x: 13, y: 4 })),
-                ("7b", (Coord { x: 16, y: 9 }, Coord { x: 16, y: 12 })),
-            ])
-        };
-        static ref EDGES: HashMap<&'static str, (Coord, Coord)> = {
-            HashMap::from([
-                ("1a", (Coord { x: 50, y: 151 }, Coord { x: 50, y: 200 })),
-                ("1b", (Coord { x: 51, y: 150 }, Coord { x: 100, y: 150 })),
-                ("2a", (Coord { x: 100, y: 51 }, Coord { x: 100, y: 100 })),
-                ("2b", (Coord { x: 101, y: 50 }, Coord { x: 150, y: 50 })),
-                ("3a", (Coord { x: 1, y: 101 }, Coord { x: 1, y: 150 })),
-                ("3b", (Coord { x: 51, y: 1 }, Coord { x: 51, y: 50 })),
-                ("4a", (Coord { x: 1, y: 101 }, Coord { x: 50, y: 101 })),
-                ("4b", (Coord { x: 51, y: 51 }, Coord { x: 51, y: 100 })),
-                ("5a", (Coord { x: 1, y: 151 }, Coord { x: 1, y: 200 })),
-                ("5b", (Coord { x: 51, y: 1 }, Coord { x: 100, y: 1 })),
-                ("6a", (Coord { x: 101, y: 1 }, Coord { x: 150, y: 1 })),
-                ("6b", (Coord { x: 1, y: 200 }, Coord { x: 50, y: 200 })),
-                ("7a", (Coord { x: 100, y: 101 }, Coord { x: 100, y: 150 })),
-                ("7b", (Coord { x: 150, y: 1 }, Coord { x: 150, y: 50 })),
-            ])
-        };
-        static ref TEST_FOLD_A: FoldType = {
-            vec![
-                (("1a", Dir::U), ("2a", Dir::U), true),
-                (("4a", Dir::U), ("3a", Dir::U), true),
-                (("4b", Dir::U), ("3b", Dir::U), true),
-                (("6b", Dir::U), ("5b", Dir::U), true),
-                (("1b", Dir::L), ("7a", Dir::L), true),
-                (("5a", Dir::L), ("6a", Dir::L), true),
-                (("2b", Dir::L), ("7b", Dir::L), true),
-            ]
-        };
-        static ref TEST_FOLD_B: FoldType = {
-            vec![
-                (("1a", Dir::U), ("1b", Dir::R), true),
-                (("2a", Dir::D), ("2b", Dir::R), false),
-                (("3a", Dir::D), ("3b", Dir::U), false),
-                (("4a", Dir::U), ("4b", Dir::D), false),
-                (("5a", Dir::L), ("5b", Dir::U), false),
-                (("6a", Dir::R), ("6b", Dir::D), false),
-                (("7a", Dir::D), ("7b", Dir::L), false),
-            ]
-        };
-        static ref FOLD_A: FoldType = {
-            vec![
-                (("4a", Dir::U), ("6b", Dir::U), true),
-                (("5b", Dir::U), ("1b", Dir::U), true),
-                (("6a", Dir::U), ("2b", Dir::U), true),
-                (("3b", Dir::L), ("7b", Dir::L), true),
-                (("4b", Dir::L), ("2a", Dir::L), true),
-                (("3a", Dir::L), ("7a", Dir::L), true),
-                (("5a", Dir::L), ("1a", Dir::L), true),
-            ]
-        };
-        static ref FOLD_B: FoldType = {
-            vec![
-                (("1a", Dir::R), ("1b", Dir::U), true),
-                (("2a", Dir::R), ("2b", Dir::U), true),
-                (("3a", Dir::L), ("3b", Dir::R), false),
-                (("4a", Dir::U), ("4b", Dir::R), true),
-                (("5a", Dir::L), ("5b", Dir::D), true),
-                (("6a", Dir::U), ("6b", Dir::U), true),
-                (("7a", Dir::R), ("7b", Dir::L), false),
-            ]
-        };
-    }
 }
@@ -0,0 +1,21 @@
+//! A square field about half full of elves, `size` is its side.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day23;
+
+impl Generate for Day23 {
+    const SIZE: usize = 72;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut row: String = (0..size.max(1))
+                    .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
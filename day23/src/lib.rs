@@ -1,75 +1,73 @@
+mod generate;
+
+use std::fmt::Display;
+
 use hashbrown::{HashMap, HashSet};
 
-use Dir::*;
+use geometry::{Dir8, Point};
+use grid::Grid;
+use render::{Frame, Recorder, Visualise};
+use solver::{ParseError, Solver};
+use Dir8::*;
+
+pub type Elf = Point<i32>;
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-struct Elf {
-    x: i32,
-    y: i32,
+fn has_neighbour(elf: Elf, elves: &HashSet<Elf>) -> bool {
+    elf.neighbours8()
+        .any(|neighbour| elves.contains(&neighbour))
 }
 
-impl Elf {
-    fn neighbour(&self, dir: &Dir) -> Self {
-        match dir {
-            N => Self {
-                y: self.y - 1,
-                x: self.x,
-            },
-            S => Self {
-                y: self.y + 1,
-                x: self.x,
-            },
-            W => Self {
-                y: self.y,
-                x: self.x - 1,
-            },
-            E => Self {
-                y: self.y,
-                x: self.x + 1,
-            },
-            NE => Self {
-                y: self.y - 1,
-                x: self.x + 1,
-            },
-            SE => Self {
-                y: self.y + 1,
-                x: self.x + 1,
-            },
-            NW => Self {
-                y: self.y - 1,
-                x: self.x - 1,
-            },
-            SW => Self {
-                y: self.y + 1,
-                x: self.x - 1,
-            },
-        }
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY: u8 = 23;
+    type Parsed<'a> = HashSet<Elf>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_elves(input)
+    }
+
+    fn part_a(elves: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_a(elves.clone(), |_| {}))
+    }
+
+    fn part_b(elves: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(part_b(elves.clone(), |_| {}))
+    }
+}
+
+/// A frame each round. The elves spread out as they go, so the rounds are collected first to
+/// draw them all on one area.
+impl Visualise for Day23 {
+    fn visualise_a(elves: &Self::Parsed<'_>, _: &(), recorder: &mut Recorder) -> impl Display {
+        let mut rounds = vec![elves.clone()];
+        let answer = part_a(elves.clone(), |elves| rounds.push(elves.clone()));
+        record(recorder, &rounds);
+        answer
     }
 
-    fn has_neighbour(&self, elves: &HashSet<Elf>) -> bool {
-        [N, S, W, E, NE, SE, NW, SW]
-            .iter()
-            .any(|dir| elves.contains(&self.neighbour(dir)))
+    fn visualise_b(
+        elves: &Self::Parsed<'_>,
+        _: &(),
+        recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        let mut rounds = vec![elves.clone()];
+        let answer = part_b(elves.clone(), |elves| rounds.push(elves.clone()));
+        record(recorder, &rounds);
+        Some(answer)
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Dir {
-    N,
-    S,
-    W,
-    E,
-    NE,
-    SE,
-    NW,
-    SW,
+pub fn solution_a(input: &str) -> Result<usize, ParseError> {
+    Ok(part_a(parse_elves(input)?, |_| {}))
+}
+
+pub fn solution_b(input: &str) -> Result<usize, ParseError> {
+    Ok(part_b(parse_elves(input)?, |_| {}))
 }
 
-pub fn solution_a(input: &str) -> usize {
-    let mut elves = parse_elves(input);
-    let y_dim = input.lines().count();
-    let x_dim = input.lines().next().unwrap().len();
-    // visualise(&elves, x_dim, y_dim);
+fn part_a(mut elves: HashSet<Elf>, mut on_round: impl FnMut(&HashSet<Elf>)) -> usize {
     let mut proposal = vec![
         ([N, NE, NW], N),
         ([S, SE, SW], S),
@@ -79,14 +77,13 @@ pub fn solution_a(input: &str) -> usize {
 
     for _ in 0..10 {
         elves = simulate_step(&elves, &mut proposal);
+        on_round(&elves);
     }
 
     score(elves)
 }
 
-pub fn solution_b(input: &str) -> usize {
-    let mut elves = parse_elves(input);
-    // visualise(&elves, x_dim, y_dim);
+fn part_b(mut elves: HashSet<Elf>, mut on_round: impl FnMut(&HashSet<Elf>)) -> usize {
     let mut proposal = vec![
         ([N, NE, NW], N),
         ([S, SE, SW], S),
@@ -96,6 +93,7 @@ pub fn solution_b(input: &str) -> usize {
 
     for step in 1.. {
         let new_elves = simulate_step(&elves, &mut proposal);
+        on_round(&new_elves);
 
         if elves.difference(&new_elves).count() == 0 {
             return step;
@@ -105,18 +103,18 @@ pub fn solution_b(input: &str) -> usize {
     0
 }
 
-fn simulate_step(elves: &HashSet<Elf>, proposal: &mut Vec<([Dir; 3], Dir)>) -> HashSet<Elf> {
+fn simulate_step(elves: &HashSet<Elf>, proposal: &mut Vec<([Dir8; 3], Dir8)>) -> HashSet<Elf> {
     // HashMap<coord to move to, elfs that would like to move to that coord>
     let mut proposed_move_counts = HashMap::<Elf, HashSet<Elf>>::new();
     let mut new_elves = HashSet::new();
     for elf in elves {
-        if !elf.has_neighbour(elves) {
+        if !has_neighbour(*elf, elves) {
             new_elves.insert(*elf);
             continue;
         }
         if let Some(proposed_dir) = propose_move(&*proposal, elves, elf) {
             proposed_move_counts
-                .entry(elf.neighbour(&proposed_dir))
+                .entry(elf.step(proposed_dir))
                 .and_modify(|elves| {
                     elves.insert(*elf);
                 })
@@ -135,7 +133,6 @@ fn simulate_step(elves: &HashSet<Elf>, proposal: &mut Vec<([Dir; 3], Dir)>) -> H
         }
     }
     proposal.rotate_left(1);
-    // visualise(&new_elves, x_dim, y_dim);
     new_elves
 }
 
@@ -149,65 +146,78 @@ fn score(elves: HashSet<Elf>) -> usize {
     x_dim * y_dim - elves.len()
 }
 
-fn propose_move(proposal: &Vec<([Dir; 3], Dir)>, elves: &HashSet<Elf>, elf: &Elf) -> Option<Dir> {
+fn propose_move(
+    proposal: &Vec<([Dir8; 3], Dir8)>,
+    elves: &HashSet<Elf>,
+    elf: &Elf,
+) -> Option<Dir8> {
     for (adj_dirs, proposed_dir) in proposal {
-        if adj_dirs
-            .iter()
-            .all(|dir| !elves.contains(&elf.neighbour(dir)))
-        {
+        if adj_dirs.iter().all(|dir| !elves.contains(&elf.step(*dir))) {
             return Some(*proposed_dir);
         }
     }
     None
 }
 
-fn parse_elves(input: &str) -> HashSet<Elf> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| {
-                if c == '.' {
-                    None
-                } else {
-                    Some(Elf {
-                        x: x as i32,
-                        y: y as i32,
-                    })
-                }
-            })
+fn parse_elves(input: &str) -> Result<HashSet<Elf>, ParseError> {
+    let grid = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let elves: HashSet<Elf> = grid
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((x, y), _)| Elf {
+            x: x as i32,
+            y: y as i32,
         })
-        .collect()
+        .collect();
+    if elves.is_empty() {
+        return Err(ParseError::new(input, input, "expected at least one elf #"));
+    }
+    Ok(elves)
 }
 
-fn visualise(elves: &HashSet<Elf>, x_dim: usize, y_dim: usize) {
-    let mut grid = vec![vec!['.'; x_dim]; y_dim];
-    for elf in elves {
-        // if elf.y as usize >= y_dim || elf.x as usize >= x_dim {
-        //     continue;
-        // }
-        grid[elf.y as usize][elf.x as usize] = '#';
+fn record(recorder: &mut Recorder, rounds: &[HashSet<Elf>]) {
+    let elves = || rounds.iter().flatten();
+    let min = Elf::new(
+        elves().map(|e| e.x).min().unwrap(),
+        elves().map(|e| e.y).min().unwrap(),
+    );
+    let max = Elf::new(
+        elves().map(|e| e.x).max().unwrap(),
+        elves().map(|e| e.y).max().unwrap(),
+    );
+    for elves in rounds {
+        recorder.frame(|| {
+            let mut frame = Frame::new(
+                (max.x - min.x + 1) as usize,
+                (max.y - min.y + 1) as usize,
+                '.',
+            );
+            for elf in elves {
+                frame[((elf.x - min.x) as usize, (elf.y - min.y) as usize)] = '#';
+            }
+            frame
+        });
     }
-    let grid_str = grid
-        .iter()
-        .map(|line| line.iter().collect::<String>())
-        .collect::<Vec<String>>()
-        .join("\n");
-    println!("{grid_str}");
-    println!();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solver::input;
 
     #[test]
     fn it_works() {
-        solution_a(TEST_INPUT);
-        assert_eq!(solution_a(TEST_INPUT_2), 110);
-        println!("{}", solution_a(INPUT));
-        assert_eq!(solution_b(TEST_INPUT_2), 20);
-        println!("{}", solution_b(INPUT));
+        let input = input::or_embedded(Day23::DAY, INPUT);
+        solution_a(TEST_INPUT).unwrap();
+        assert_eq!(solution_a(TEST_INPUT_2), Ok(110));
+        println!("{}", solution_a(&input).unwrap());
+        assert_eq!(solution_b(TEST_INPUT_2), Ok(20));
+        println!("{}", solution_b(&input).unwrap());
+        assert!(solution_a(".....\n").is_err());
     }
 
     const TEST_INPUT: &str = ".....
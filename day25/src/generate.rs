@@ -0,0 +1,22 @@
+//! Fuel requirements in SNAFU, `size` counts them.
+
+use generator::{rand::Rng, Generate};
+
+use crate::Day25;
+
+impl Generate for Day25 {
+    const SIZE: usize = 111;
+
+    fn generate(size: usize, rng: &mut impl Rng) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut snafu = ["1", "2"][rng.gen_range(0..2)].to_string();
+                for _ in 0..rng.gen_range(0..20) {
+                    snafu.push(['=', '-', '0', '1', '2'][rng.gen_range(0..5)]);
+                }
+                snafu.push('\n');
+                snafu
+            })
+            .collect()
+    }
+}
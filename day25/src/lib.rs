@@ -1,20 +1,54 @@
-pub fn solution(input: &str) -> String {
-    let snafus = input.lines().map(to_decimal).collect::<Vec<i64>>();
+mod generate;
+
+use std::fmt::Display;
+
+use solver::{ParseError, Solver};
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u8 = 25;
+    type Parsed<'a> = Vec<i64>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(snafus: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+        Some(to_snafu(snafus.iter().sum()))
+    }
+}
+
+pub fn solution(input: &str) -> Result<String, ParseError> {
+    let snafus = parse(input)?;
     let sum = snafus.iter().sum();
-    to_snafu(sum)
+    Ok(to_snafu(sum))
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            to_decimal(line).map_err(|i| {
+                ParseError::new(input, &line[i..], "expected a SNAFU digit 2, 1, 0, - or =")
+            })
+        })
+        .collect()
 }
 
-fn to_decimal(snafu: &str) -> i64 {
-    snafu.chars().fold(0, |decimal, c| {
-        decimal * 5
-            + match c {
-                '0' => 0,
-                '1' => 1,
-                '2' => 2,
-                '=' => -2,
-                '-' => -1,
-                _ => unreachable!(),
-            }
+/// The index of the first character that isn't a SNAFU digit on error.
+fn to_decimal(snafu: &str) -> Result<i64, usize> {
+    snafu.char_indices().try_fold(0, |decimal, (i, c)| {
+        let digit = match c {
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            '=' => -2,
+            '-' => -1,
+            _ => return Err(i),
+        };
+        Ok(decimal * 5 + digit)
     })
 }
 
@@ -37,11 +71,26 @@ fn to_snafu(decimal: i64) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use solver::input;
 
     #[test]
     fn it_works() {
-        assert_eq!(solution(TEST_INPUT), "2=-1=0");
-        println!("{}", solution(INPUT));
+        let input = input::or_embedded(Day25::DAY, INPUT);
+        assert_eq!(solution(TEST_INPUT).as_deref(), Ok("2=-1=0"));
+        println!("{}", solution(&input).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn decimal_round_trips(decimal in 0..i64::MAX / 5) {
+            prop_assert_eq!(to_decimal(&to_snafu(decimal)), Ok(decimal));
+        }
+
+        #[test]
+        fn snafu_round_trips(snafu in "[12][=\\-012]{0,20}") {
+            prop_assert_eq!(to_snafu(to_decimal(&snafu).unwrap()), snafu);
+        }
     }
 
     const TEST_INPUT: &str = "1=-0-2
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day13::Day13;
+use libfuzzer_sys::fuzz_target;
+use solver::Solver;
+
+fuzz_target!(|input: &str| {
+    let _ = Day13::parse(input);
+});
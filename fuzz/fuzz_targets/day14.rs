@@ -0,0 +1,9 @@
+#![no_main]
+
+use day14::Day14;
+use libfuzzer_sys::fuzz_target;
+use solver::Solver;
+
+fuzz_target!(|input: &str| {
+    let _ = Day14::parse(input);
+});
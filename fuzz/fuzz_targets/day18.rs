@@ -0,0 +1,9 @@
+#![no_main]
+
+use day18::Day18;
+use libfuzzer_sys::fuzz_target;
+use solver::Solver;
+
+fuzz_target!(|input: &str| {
+    let _ = Day18::parse(input);
+});
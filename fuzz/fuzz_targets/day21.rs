@@ -0,0 +1,9 @@
+#![no_main]
+
+use day21::Day21;
+use libfuzzer_sys::fuzz_target;
+use solver::Solver;
+
+fuzz_target!(|input: &str| {
+    let _ = Day21::parse(input);
+});
@@ -0,0 +1,31 @@
+//! Makes up puzzle inputs of any size, to stress test and benchmark the solutions beyond the one
+//! real input and the examples each day has.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use solver::{Day, Puzzle, Solver};
+
+pub use rand;
+
+/// A [`Solver`] that can make up inputs for itself.
+pub trait Generate: Solver {
+    /// Size of the real input, in whatever [`Generate::generate`] counts.
+    const SIZE: usize;
+
+    /// An input that parses and has an answer. What `size` counts depends on the day, e.g. lines,
+    /// elves or the side of a map.
+    fn generate(size: usize, rng: &mut impl Rng) -> String;
+}
+
+/// Object safe view of a [`Generate`], like [`Puzzle`] is of a [`Solver`].
+pub trait Generator: Puzzle {
+    /// An input of `size`, the size of the real input when `None`. The same seed makes the same
+    /// input.
+    fn generate(&self, size: Option<usize>, seed: u64) -> String;
+}
+
+impl<S: Generate> Generator for Day<S> {
+    fn generate(&self, size: Option<usize>, seed: u64) -> String {
+        let mut rng = StdRng::seed_from_u64(seed);
+        S::generate(size.unwrap_or(S::SIZE), &mut rng)
+    }
+}
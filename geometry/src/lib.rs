@@ -0,0 +1,371 @@
+//! Points and directions shared by the puzzles that walk around a map. `y` grows downwards like
+//! the rows of the input, so [`Dir4::Up`] takes one off `y`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num_traits::{PrimInt, Signed};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: PrimInt> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Distance counting diagonal steps as one, so touching points are 1 apart.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// The point one step away in `dir`. Overflows like the underlying integers do, so unsigned
+    /// points have to stay clear of zero.
+    pub fn step(self, dir: impl Into<Dir8>) -> Self {
+        let (dx, dy) = dir.into().offset();
+        Self {
+            x: shift(self.x, dx),
+            y: shift(self.y, dy),
+        }
+    }
+
+    /// Up, right, down and left neighbours.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// [`Point::neighbours4`] and the diagonal neighbours.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+}
+
+impl<T: Signed> Point<T> {
+    /// `-1`, `0` or `1` in each axis, the single step that moves towards this vector.
+    pub fn signum(&self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: PrimInt> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| Self {
+            x: shift(self.x, dx),
+            y: shift(self.y, dy),
+            z: shift(self.z, dz),
+        })
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/// Which way to turn a direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// The four directions along the axes, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// The compass directions including the diagonals, clockwise from north. North is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    fn offset(self) -> (i8, i8) {
+        match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Moves `n` by `delta` of -1, 0 or 1 without needing `T` to be signed.
+fn shift<T: PrimInt>(n: T, delta: i8) -> T {
+    match delta {
+        1 => n + T::one(),
+        -1 => n - T::one(),
+        _ => n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let a = Point::new(1, -2);
+        let b = Point::new(4, 2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, 4));
+        assert_eq!(-(a * 2), Point::new(-2, 4));
+        assert_eq!((b - a).signum(), Point::new(1, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::new(5u32, 1).manhattan(Point::new(2, 3)), 5);
+
+        assert_eq!(Point::new(1u32, 1).step(Dir4::Up), Point::new(1, 0));
+        assert_eq!(Point::new(1u32, 1).step(Dir8::SW), Point::new(0, 2));
+        assert_eq!(Point::new(0, 0).neighbours4().count(), 4);
+        assert_eq!(
+            Point::new(0, 0).neighbours8().collect::<Vec<_>>()[1],
+            Point::new(1, -1)
+        );
+
+        assert_eq!(Dir4::Up.turn(Turn::Left), Dir4::Left);
+        assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+        assert_eq!(Dir4::Right.opposite(), Dir4::Left);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.opposite(), Dir8::SW);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+
+        let cube = Point3::new(1, 2, 3);
+        assert!(cube
+            .neighbours6()
+            .all(|neighbour| neighbour.manhattan(cube) == 1));
+        assert_eq!(cube + Point3::new(1, 1, 1) - cube, Point3::new(1, 1, 1));
+        assert_eq!(-cube, Point3::new(-1, -2, -3));
+    }
+}
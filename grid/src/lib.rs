@@ -0,0 +1,268 @@
+//! Dense 2D maps parsed from the puzzle text, indexed by `(x, y)` with `(0, 0)` top left.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use solver::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// One row per line, `cell` maps each character and returns `None` for ones it doesn't expect.
+    /// Every row has to be as wide as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = vec![];
+        for line in input.lines() {
+            let before = cells.len();
+            parse_line(input, line, &mut cell, &mut cells)?;
+            if cells.len() - before != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("expected {width} cells like the first row"),
+                ));
+            }
+        }
+        Ok(Self {
+            width,
+            height: input.lines().count(),
+            cells,
+        })
+    }
+
+    /// Like [`Grid::parse`] but rows may differ in width, short ones are padded with `fill` to
+    /// the widest row.
+    pub fn parse_padded(
+        input: &str,
+        fill: T,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = vec![];
+        for line in input.lines() {
+            let before = cells.len();
+            parse_line(input, line, &mut cell, &mut cells)?;
+            cells.resize(before + width, fill.clone());
+        }
+        Ok(Self {
+            width,
+            height: input.lines().count(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        let index = self.index(position);
+        Some(&mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks_exact panics on zero, an empty grid has no cells to chunk anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Up, down, left and right neighbours that are inside the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &[(0, -1), (0, 1), (-1, 0), (1, 0)])
+    }
+
+    /// [`Grid::neighbours4`] and the diagonal neighbours.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(
+            position,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn neighbours(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let neighbour = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+
+    fn index(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+}
+
+fn parse_line<T>(
+    input: &str,
+    line: &str,
+    cell: &mut impl FnMut(char) -> Option<T>,
+    cells: &mut Vec<T>,
+) -> Result<(), ParseError> {
+    for (i, c) in line.char_indices() {
+        match cell(c) {
+            Some(value) => cells.push(value),
+            None => {
+                return Err(ParseError::new(
+                    input,
+                    &line[i..],
+                    format!("unexpected character {c:?}"),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        assert!(self.contains(position), "{position:?} out of bounds");
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        assert!(self.contains(position), "{position:?} out of bounds");
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+}
+
+/// One line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let grid = Grid::parse("123\n456", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).rev().collect::<Vec<_>>(), vec![&5, &2]);
+        assert_eq!(grid.position(|n| *n == 5), Some((1, 1)));
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let err = Grid::parse("123\n4x6", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert!(Grid::parse("123\n45", |c| c.to_digit(10)).is_err());
+
+        let padded = Grid::parse_padded(" .#\n.", ' ', Some).unwrap();
+        assert_eq!(padded.to_string(), " .#\n.  ");
+    }
+}
@@ -0,0 +1,302 @@
+//! Ranges of integers with both ends included, and sets of them, for the puzzles that compare or
+//! merge ranges of sections or positions rather than every integer in them.
+
+use std::fmt::{self, Display};
+
+use num_traits::PrimInt;
+
+/// The integers from `start` to `end`, both included, so never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    /// `None` when `start` is after `end`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn start(self) -> T {
+        self.start
+    }
+
+    pub fn end(self) -> T {
+        self.end
+    }
+
+    /// How many integers it has. Overflows like `T` does for every integer of `T`.
+    pub fn len(self) -> T {
+        self.end - self.start + T::one()
+    }
+
+    pub fn contains(self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every integer of `other` is in this one.
+    pub fn covers(self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Whether this one ends before `other` starts with a gap between them, so they don't merge.
+    fn before(self, other: Self) -> bool {
+        self.end
+            .checked_add(&T::one())
+            .is_some_and(|after| after < other.start)
+    }
+}
+
+/// Like the puzzle input writes them, e.g. `2-4`.
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers kept as the intervals it's made of, in order and merged wherever they
+/// overlap or touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers it has.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |len, interval| len + interval.len())
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let first = self
+            .intervals
+            .partition_point(|other| other.before(interval));
+        let last = self
+            .intervals
+            .partition_point(|other| !interval.before(*other));
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| Interval {
+                start: merged.start.min(other.start),
+                end: merged.end.max(other.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|other| other.end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|other| other.contains(value))
+    }
+
+    /// Whether every integer of `interval` is in the set.
+    pub fn covers(&self, interval: Interval<T>) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        self.intervals
+            .get(i)
+            .is_some_and(|other| other.covers(interval))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(**y));
+            // the one that ends first can't overlap anything further on in the other set
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let intervals = self
+            .intervals
+            .iter()
+            .flat_map(|interval| other.gaps(*interval).intervals)
+            .collect();
+        Self { intervals }
+    }
+
+    /// The integers of `within` that aren't in the set.
+    pub fn gaps(&self, within: Interval<T>) -> Self {
+        let mut intervals = vec![];
+        // the first integer of `within` that isn't in the set as far as it's been checked
+        let mut from = Some(within.start);
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < within.start);
+        for other in &self.intervals[first..] {
+            let Some(start) = from else { break };
+            if other.start > within.end {
+                break;
+            }
+            if other.start > start {
+                intervals.push(Interval {
+                    start,
+                    end: other.start - T::one(),
+                });
+            }
+            from = other.end.checked_add(&T::one());
+        }
+        if let Some(start) = from.filter(|start| *start <= within.end) {
+            intervals.push(Interval {
+                start,
+                end: within.end,
+            });
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        // sorted, each interval merges into the last one or goes after it
+        let mut intervals: Vec<_> = iter.into_iter().collect();
+        intervals.sort_unstable();
+        let mut set = Self::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn it_works() {
+        let a = Interval::new(2, 4).unwrap();
+        let b = Interval::new(4, 8).unwrap();
+        assert!(Interval::new(4, 2).is_none());
+        assert_eq!(a.len(), 3);
+        assert!(a.overlaps(b) && !a.covers(b));
+        assert_eq!(a.intersection(b), Interval::new(4, 4));
+        assert_eq!(a.to_string(), "2-4");
+
+        let merged = set(&[(5, 7), (1, 2), (3, 3), (10, 12)]);
+        assert_eq!(merged, set(&[(1, 3), (5, 7), (10, 12)]));
+        assert_eq!(merged.len(), 9);
+        assert!(merged.contains(6) && !merged.contains(4));
+        assert!(merged.covers(Interval::new(1, 3).unwrap()));
+        assert_eq!(
+            merged.gaps(Interval::new(0, 11).unwrap()),
+            set(&[(0, 0), (4, 4), (8, 9)])
+        );
+        assert_eq!(
+            merged.difference(&set(&[(2, 6)])),
+            set(&[(1, 1), (7, 7), (10, 12)])
+        );
+        assert_eq!(
+            merged.intersection(&set(&[(2, 11)])),
+            set(&[(2, 3), (5, 7), (10, 11)])
+        );
+
+        let edge = IntervalSet::from_iter([Interval::new(u8::MAX - 1, u8::MAX).unwrap()]);
+        assert!(edge
+            .gaps(Interval::new(250, u8::MAX).unwrap())
+            .covers(Interval::new(250, 253).unwrap()));
+        assert_eq!(
+            edge.gaps(Interval::new(254, 255).unwrap()),
+            IntervalSet::new()
+        );
+    }
+
+    fn intervals() -> impl Strategy<Value = Vec<(i32, i32)>> {
+        prop::collection::vec(
+            (-20..20, 0..10).prop_map(|(start, len)| (start, start + len)),
+            0..6,
+        )
+    }
+
+    fn values(intervals: &[(i32, i32)]) -> BTreeSet<i32> {
+        intervals
+            .iter()
+            .flat_map(|(start, end)| *start..=*end)
+            .collect()
+    }
+
+    fn set_values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.intervals()
+            .iter()
+            .flat_map(|interval| interval.start()..=interval.end())
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_btree_set(a in intervals(), b in intervals()) {
+            let (set_a, set_b) = (set(&a), set(&b));
+            let (values_a, values_b) = (values(&a), values(&b));
+            // merged, so no two intervals overlap or touch
+            prop_assert!(set_a.intervals().windows(2).all(|pair| pair[0].before(pair[1])));
+            prop_assert_eq!(set_a.len() as usize, values_a.len());
+            prop_assert_eq!(set_values(&set_a.union(&set_b)), &values_a | &values_b);
+            prop_assert_eq!(set_values(&set_a.intersection(&set_b)), &values_a & &values_b);
+            prop_assert_eq!(set_values(&set_a.difference(&set_b)), &values_a - &values_b);
+            let within = Interval::new(-10, 10).unwrap();
+            let gaps: BTreeSet<_> = (-10..=10).filter(|value| !values_a.contains(value)).collect();
+            prop_assert_eq!(set_values(&set_a.gaps(within)), gaps);
+            for value in -25..35 {
+                prop_assert_eq!(set_a.contains(value), values_a.contains(&value));
+            }
+        }
+    }
+}
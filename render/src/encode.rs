@@ -0,0 +1,163 @@
+//! Colours frames in and encodes them as images.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    iter,
+    path::Path,
+    time::Duration,
+};
+
+use grid::Grid;
+
+use crate::Frame;
+
+/// Colours of the characters the puzzles draw with, anything else is [`OTHER`]. The first entry
+/// also fills out frames smaller than the canvas of an animation.
+const PALETTE: &[(char, [u8; 3])] = &[
+    (' ', [0, 0, 0]),
+    ('.', [24, 24, 40]),
+    ('#', [140, 140, 150]),
+    ('|', [90, 90, 100]),
+    ('-', [90, 90, 100]),
+    ('o', [240, 200, 80]),
+    ('+', [255, 90, 60]),
+    ('@', [90, 210, 120]),
+    ('H', [255, 80, 80]),
+    ('T', [255, 170, 80]),
+    ('s', [90, 160, 255]),
+    ('^', [90, 160, 255]),
+    ('v', [90, 160, 255]),
+    ('<', [90, 160, 255]),
+    ('>', [90, 160, 255]),
+];
+
+const OTHER: [u8; 3] = [255, 255, 255];
+
+pub(crate) fn indices(frame: &Frame) -> Grid<u8> {
+    frame.map(|c| {
+        PALETTE
+            .iter()
+            .position(|(p, _)| p == c)
+            .unwrap_or(PALETTE.len()) as u8
+    })
+}
+
+fn palette() -> Vec<u8> {
+    PALETTE
+        .iter()
+        .map(|(_, rgb)| rgb)
+        .chain([&OTHER])
+        .flatten()
+        .copied()
+        .collect()
+}
+
+/// Palette indices of `frame` with every cell blown up to `scale` by `scale` pixels, padded out to
+/// `width` by `height` cells.
+fn pixels(frame: &Grid<u8>, width: usize, height: usize, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height * scale * scale);
+    for y in 0..height {
+        let row: Vec<u8> = (0..width)
+            .flat_map(|x| iter::repeat_n(frame.get((x, y)).copied().unwrap_or(0), scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&row);
+        }
+    }
+    pixels
+}
+
+/// Size in cells of a canvas that fits every frame.
+fn canvas(frames: &[Grid<u8>]) -> (usize, usize) {
+    let width = frames.iter().map(Grid::width).max().unwrap_or(0);
+    let height = frames.iter().map(Grid::height).max().unwrap_or(0);
+    (width.max(1), height.max(1))
+}
+
+pub(crate) fn png(path: &Path, frame: &Frame, scale: usize) -> io::Result<()> {
+    let frame = indices(frame);
+    let (width, height) = canvas(std::slice::from_ref(&frame));
+    let mut encoder = png_encoder(
+        BufWriter::new(File::create(path)?),
+        width * scale,
+        height * scale,
+    )?;
+    encoder.set_compression(png::Compression::Fast);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&pixels(&frame, width, height, scale))
+        .map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+pub(crate) fn apng(
+    out: impl Write,
+    frames: &[Grid<u8>],
+    scale: usize,
+    delay: Duration,
+) -> io::Result<()> {
+    let (width, height) = canvas(frames);
+    let mut encoder = png_encoder(out, width * scale, height * scale)?;
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_frame_delay(delay.as_millis().min(u16::MAX as u128) as u16, 1000)
+        .map_err(io::Error::other)?;
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for frame in frames {
+        writer
+            .write_image_data(&pixels(frame, width, height, scale))
+            .map_err(io::Error::other)?;
+    }
+    writer.finish().map_err(io::Error::other)
+}
+
+fn png_encoder<W: Write>(
+    out: W,
+    width: usize,
+    height: usize,
+) -> io::Result<png::Encoder<'static, W>> {
+    let (Ok(width), Ok(height)) = (u32::try_from(width), u32::try_from(height)) else {
+        return Err(io::Error::other("frame too large for a PNG"));
+    };
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette());
+    Ok(encoder)
+}
+
+pub(crate) fn gif(
+    out: impl Write,
+    frames: &[Grid<u8>],
+    scale: usize,
+    delay: Duration,
+) -> io::Result<()> {
+    let (width, height) = canvas(frames);
+    let (Ok(width_px), Ok(height_px)) =
+        (u16::try_from(width * scale), u16::try_from(height * scale))
+    else {
+        return Err(io::Error::other(
+            "frame too large for a GIF, try a smaller scale",
+        ));
+    };
+    let mut encoder =
+        gif::Encoder::new(out, width_px, height_px, &palette()).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for frame in frames {
+        let mut frame = gif::Frame::from_indexed_pixels(
+            width_px,
+            height_px,
+            pixels(frame, width, height, scale),
+            None,
+        );
+        // GIF delays are in hundredths of a second
+        frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
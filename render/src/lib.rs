@@ -0,0 +1,307 @@
+//! Records a simulation step by step as frames of characters, like the puzzles' own diagrams, and
+//! writes them out as ASCII text, PNG images or a GIF or APNG animation.
+
+mod encode;
+
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufWriter, IsTerminal, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::Duration,
+};
+
+use grid::Grid;
+use solver::{Day, ParseError, Part, Puzzle, Solver};
+
+/// One step of a simulation, a character per cell.
+pub type Frame = Grid<char>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Ascii,
+    /// A numbered image per frame.
+    Png,
+    Gif,
+    Apng,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ascii => "txt",
+            Format::Png | Format::Apng => "png",
+            Format::Gif => "gif",
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Ascii => write!(f, "ascii"),
+            Format::Png => write!(f, "png"),
+            Format::Gif => write!(f, "gif"),
+            Format::Apng => write!(f, "apng"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(Format::Ascii),
+            "png" => Ok(Format::Png),
+            "gif" => Ok(Format::Gif),
+            "apng" => Ok(Format::Apng),
+            _ => Err(format!(
+                "unknown format {s:?}, expected ascii, png, gif or apng"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub format: Format,
+    /// Where to write the frames, ASCII goes to stdout when `None`. PNG frames are numbered after
+    /// the file's stem, `day14.png` becomes `day14-00001.png` and so on.
+    pub output: Option<PathBuf>,
+    /// Keep every nth frame, some simulations run for tens of thousands of steps.
+    pub every: usize,
+    /// Time between the frames of an animation, or of ASCII played back in a terminal.
+    pub delay: Duration,
+    /// Pixels per cell in images.
+    pub scale: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            format: Format::Ascii,
+            output: None,
+            every: 1,
+            delay: Duration::from_millis(50),
+            scale: 4,
+        }
+    }
+}
+
+/// Collects the frames of one run of a simulation.
+pub struct Recorder {
+    options: Options,
+    steps: usize,
+    frames: usize,
+    sink: Sink,
+    /// First write that failed, the simulation carries on and [`Recorder::finish`] reports it.
+    error: Option<io::Error>,
+}
+
+enum Sink {
+    Ascii {
+        out: Box<dyn Write>,
+        terminal: bool,
+    },
+    Png {
+        path: PathBuf,
+    },
+    /// The canvas of an animation fits the largest frame, so frames are only encoded at the end.
+    /// They're kept as palette indices in the meantime.
+    Animation {
+        path: PathBuf,
+        frames: Vec<Grid<u8>>,
+    },
+}
+
+impl Recorder {
+    pub fn new(options: Options) -> io::Result<Self> {
+        let sink = match (options.format, &options.output) {
+            (Format::Ascii, None) => Sink::Ascii {
+                out: Box::new(io::stdout()),
+                terminal: io::stdout().is_terminal(),
+            },
+            (Format::Ascii, Some(path)) => Sink::Ascii {
+                out: Box::new(BufWriter::new(File::create(path)?)),
+                terminal: false,
+            },
+            (format, None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{format} needs an output path"),
+                ))
+            }
+            (Format::Png, Some(path)) => Sink::Png { path: path.clone() },
+            (Format::Gif | Format::Apng, Some(path)) => Sink::Animation {
+                path: path.clone(),
+                frames: vec![],
+            },
+        };
+        Ok(Self {
+            options,
+            steps: 0,
+            frames: 0,
+            sink,
+            error: None,
+        })
+    }
+
+    /// Records a step of the simulation, `draw` is only called for the steps that are kept.
+    pub fn frame(&mut self, draw: impl FnOnce() -> Frame) {
+        let step = self.steps;
+        self.steps += 1;
+        if self.error.is_some() || !step.is_multiple_of(self.options.every.max(1)) {
+            return;
+        }
+        match self.write(&draw()) {
+            Ok(()) => self.frames += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    /// Writes out anything still buffered and returns the number of frames recorded. Nothing is
+    /// written for an animation without frames.
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        match self.sink {
+            Sink::Ascii { mut out, .. } => out.flush()?,
+            Sink::Png { .. } => {}
+            Sink::Animation { frames, .. } if frames.is_empty() => {}
+            Sink::Animation { path, frames } => {
+                let out = BufWriter::new(File::create(path)?);
+                let (scale, delay) = (self.options.scale, self.options.delay);
+                match self.options.format {
+                    Format::Gif => encode::gif(out, &frames, scale, delay)?,
+                    _ => encode::apng(out, &frames, scale, delay)?,
+                }
+            }
+        }
+        Ok(self.frames)
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        match &mut self.sink {
+            Sink::Ascii { out, terminal } => {
+                if *terminal {
+                    // clear the screen so the frames play in place
+                    write!(out, "\x1b[H\x1b[2J")?;
+                }
+                writeln!(out, "{frame}\n")?;
+                if *terminal {
+                    out.flush()?;
+                    thread::sleep(self.options.delay);
+                }
+                Ok(())
+            }
+            Sink::Png { path } => {
+                encode::png(&numbered(path, self.frames + 1), frame, self.options.scale)
+            }
+            Sink::Animation { frames, .. } => {
+                frames.push(encode::indices(frame));
+                Ok(())
+            }
+        }
+    }
+}
+
+fn numbered(path: &Path, n: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or("png".as_ref()).to_string_lossy();
+    path.with_file_name(format!("{stem}-{n:05}.{extension}"))
+}
+
+/// A [`Solver`] that can record its simulation while solving.
+pub trait Visualise: Solver {
+    fn visualise_a(
+        parsed: &Self::Parsed<'_>,
+        params: &Self::Params,
+        recorder: &mut Recorder,
+    ) -> impl Display;
+
+    /// `None` when part B has no simulation to watch.
+    fn visualise_b(
+        _parsed: &Self::Parsed<'_>,
+        _params: &Self::Params,
+        _recorder: &mut Recorder,
+    ) -> Option<impl Display> {
+        None::<String>
+    }
+}
+
+/// Object safe view of a [`Visualise`], like [`Puzzle`] is of a [`Solver`].
+pub trait Animation: Puzzle {
+    /// Parses the input and solves `part` while recording it, `None` when the part can't be
+    /// visualised.
+    fn animate(
+        &self,
+        input: &str,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<Option<String>, ParseError>;
+}
+
+impl<S: Visualise> Animation for Day<S> {
+    fn animate(
+        &self,
+        input: &str,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<Option<String>, ParseError> {
+        let parsed = S::parse(input)?;
+        let params = S::Params::default();
+        Ok(match part {
+            Part::A => Some(S::visualise_a(&parsed, &params, recorder).to_string()),
+            Part::B => S::visualise_b(&parsed, &params, recorder).map(|answer| answer.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    #[test]
+    fn it_works() {
+        assert_eq!("apng".parse(), Ok(Format::Apng));
+        assert!("jpeg".parse::<Format>().is_err());
+        assert_eq!(
+            numbered(Path::new("out/day14.png"), 3),
+            Path::new("out/day14-00003.png")
+        );
+
+        let dir = env::temp_dir().join(format!("render-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for format in [Format::Ascii, Format::Png, Format::Gif, Format::Apng] {
+            let output = dir.join(format!("frames.{format}"));
+            let mut recorder = Recorder::new(Options {
+                format,
+                output: Some(output.clone()),
+                every: 2,
+                ..Options::default()
+            })
+            .unwrap();
+            for step in 0..5 {
+                recorder.frame(|| Grid::new(step + 1, 2, '#'));
+            }
+            assert_eq!(recorder.finish().unwrap(), 3);
+            let written = match format {
+                Format::Png => numbered(&output, 3),
+                _ => output,
+            };
+            assert!(fs::metadata(written).unwrap().len() > 0);
+        }
+        fs::remove_dir_all(dir).unwrap();
+
+        let err = Recorder::new(Options {
+            format: Format::Gif,
+            ..Options::default()
+        });
+        assert!(err.is_err());
+    }
+}
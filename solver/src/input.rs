@@ -0,0 +1,92 @@
+//! Finding puzzle inputs at runtime, so a different input doesn't mean editing the source.
+
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming the inputs directory, laid out as `<dir>/2022/day14.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+const YEAR: u16 = 2022;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    /// The day's file in the inputs directory.
+    InputDir(u8),
+}
+
+impl Source {
+    /// `-` reads stdin, any other path is read as a file and no path looks in the inputs directory.
+    pub fn new(day: u8, path: Option<&Path>) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_path_buf()),
+            None => Source::InputDir(day),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+            Source::InputDir(day) => fs::read_to_string(day_path(&input_dir(), *day)),
+        }
+    }
+
+    /// Human readable description for error messages.
+    pub fn describe(&self) -> String {
+        match self {
+            Source::Stdin => "stdin".to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::InputDir(day) => day_path(&input_dir(), *day).display().to_string(),
+        }
+    }
+}
+
+/// `$AOC_INPUT_DIR`, or `inputs` in the working directory when it isn't set.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(YEAR.to_string()).join(format!("day{day:02}.txt"))
+}
+
+/// The day's input from `$AOC_INPUT_DIR` when it is set and has the file, otherwise the input
+/// embedded in the crate. Only meant for tests, which predate runtime inputs.
+pub fn or_embedded(day: u8, embedded: &'static str) -> Cow<'static, str> {
+    env::var_os(INPUT_DIR_VAR)
+        .and_then(|dir| fs::read_to_string(day_path(Path::new(&dir), day)).ok())
+        .map_or(Cow::Borrowed(embedded), Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(Source::new(14, None), Source::InputDir(14));
+        assert_eq!(Source::new(14, Some(Path::new("-"))), Source::Stdin);
+        assert_eq!(
+            Source::new(14, Some(Path::new("day14.txt"))),
+            Source::File(PathBuf::from("day14.txt"))
+        );
+        assert_eq!(
+            day_path(Path::new("inputs"), 4),
+            Path::new("inputs").join("2022").join("day04.txt")
+        );
+    }
+}
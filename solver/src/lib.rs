@@ -0,0 +1,173 @@
+pub mod input;
+pub mod parse;
+
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+pub use parse::{ParseError, ReadError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format!("unknown part {s:?}, expected a or b")),
+        }
+    }
+}
+
+/// A day's puzzle, parsed once and then solved for either part.
+pub trait Solver {
+    const DAY: u8;
+
+    /// Parsed puzzle input, shared by both parts.
+    type Parsed<'a>;
+
+    /// Per-day knobs such as the number of rope knots, the default is what the real input uses.
+    type Params: Default;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    /// `None` when the input has no answer, e.g. a datastream without a marker.
+    fn part_a(parsed: &Self::Parsed<'_>, params: &Self::Params) -> Option<impl Display>;
+
+    /// `None` when the day has no solution for part B, or the input has no answer.
+    fn part_b(_parsed: &Self::Parsed<'_>, _params: &Self::Params) -> Option<impl Display> {
+        None::<String>
+    }
+}
+
+/// Answers from one run of a [`Puzzle`] and how long each step took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    /// `None` when the puzzle or the input has no solution for the part.
+    pub value: Option<String>,
+    pub solve_time: Duration,
+}
+
+/// Object safe view of a [`Solver`], so days with different types can be driven from one list.
+pub trait Puzzle: Send + Sync {
+    fn day(&self) -> u8;
+
+    /// Name of the crate implementing the puzzle, e.g. `day04_nom`.
+    fn name(&self) -> &'static str;
+
+    /// Parses the input once and solves the requested parts in order.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Solution, ParseError>;
+}
+
+pub struct Day<S> {
+    name: &'static str,
+    solver: PhantomData<fn() -> S>,
+}
+
+impl<S: Solver> Day<S> {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            solver: PhantomData,
+        }
+    }
+}
+
+impl<S: Solver> Puzzle for Day<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Solution, ParseError> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_time = start.elapsed();
+        let params = S::Params::default();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let value = match part {
+                    Part::A => S::part_a(&parsed, &params).map(|answer| answer.to_string()),
+                    Part::B => S::part_b(&parsed, &params).map(|answer| answer.to_string()),
+                };
+                Answer {
+                    part,
+                    value,
+                    solve_time: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(Solution {
+            parse_time,
+            answers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solver for Lines {
+        const DAY: u8 = 1;
+        type Parsed<'a> = Vec<&'a str>;
+        type Params = ();
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_a(lines: &Self::Parsed<'_>, _: &()) -> Option<impl Display> {
+            Some(lines.len())
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let puzzle = Day::<Lines>::new("lines");
+        assert_eq!(puzzle.day(), 1);
+        let solution = puzzle.solve("a\nb\nc", &[Part::A, Part::B]).unwrap();
+        assert_eq!(
+            solution
+                .answers
+                .iter()
+                .map(|answer| (answer.part, answer.value.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(Part::A, Some("3")), (Part::B, None)]
+        );
+        assert_eq!("b".parse(), Ok(Part::B));
+        assert!("c".parse::<Part>().is_err());
+    }
+}
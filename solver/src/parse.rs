@@ -0,0 +1,149 @@
+//! Errors for malformed puzzle input, pointing at the line and column parsing stopped at.
+
+use std::{error::Error, fmt, io, str::FromStr};
+
+use nom::{error::ErrorKind, IResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column of the offending text, counted in characters.
+    pub column: usize,
+    /// The offending text up to the end of its line, empty at the end of the input.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// `at` has to be a slice of `input`, e.g. a line from `input.lines()`, a token split off a
+    /// line or the remaining input of a nom parser.
+    pub fn new(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "`at` is not a slice of `input`");
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: at.lines().next().unwrap_or_default().to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, " at end of line")
+        } else {
+            write!(f, " at {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reading puzzle input a bit at a time failed, either the reader or the input it read.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => err.fmt(f),
+            ReadError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// Parses `token`, a slice of `input`, into a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(input, token, "expected a number"))
+}
+
+/// Turns the result of a nom parser run on the whole `input` into a [`ParseError`], rejecting
+/// anything but whitespace left after the parser stopped.
+pub fn finish<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((rest, parsed)) if rest.trim().is_empty() => Ok(parsed),
+        Ok((rest, _)) => {
+            // report the first character that isn't whitespace rather than a trailing newline
+            let rest = rest.trim_start();
+            Err(ParseError::new(input, rest, "unexpected trailing input"))
+        }
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(ParseError::new(input, err.input, expected(err.code)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+fn expected(code: ErrorKind) -> String {
+    match code {
+        ErrorKind::Tag => "unexpected text".to_string(),
+        ErrorKind::Digit => "expected a number".to_string(),
+        ErrorKind::Char => "unexpected character".to_string(),
+        ErrorKind::TooLarge => "nested too deeply".to_string(),
+        code => format!("invalid input ({})", code.description()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete, multi::separated_list1};
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(complete::newline, complete::u32)(input)
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "1\n2\n3\n";
+        assert_eq!(finish(input, numbers(input)), Ok(vec![1, 2, 3]));
+
+        let input = "1\n2x\n3\n";
+        let err = finish(input, numbers(input)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected trailing input at \"x\""
+        );
+
+        let input = "1 2\nabc";
+        let line = input.lines().nth(1).unwrap();
+        let err = number::<u32>(input, line).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(number::<u32>(input, &input[2..3]), Ok(2));
+    }
+}